@@ -0,0 +1,96 @@
+//! Scraping benchmarks over the HTML pages in `assets/`.
+//!
+//! The page fixtures are not checked in, so without a local copy every group runs
+//! on one synthetic page and the numbers do not reflect real articles.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use metagraph::{open_graph::BorrowedOpenGraphObject, OpenGraphScraper, Scraper, TwitterScraper};
+use scraper::Html;
+use std::{fs, hint::black_box};
+use url::Url;
+
+/// A page with a typical article's metadata, used when no `assets/` fixtures are present.
+fn synthetic_page() -> String {
+    let mut head = String::from("<head><title>Article</title>");
+    head += r#"<meta property="og:title" content="Title" />"#;
+    head += r#"<meta property="og:type" content="article" />"#;
+    head += r#"<meta property="og:url" content="https://x.com/article" />"#;
+    for index in 0..8 {
+        head += &format!(
+            r#"<meta property="og:image" content="/{index}.jpg" />
+            <meta property="og:image:width" content="1200" />
+            <meta property="og:image:height" content="630" />
+            <meta property="og:image:alt" content="Image {index}" />"#
+        );
+    }
+    head += r#"<meta name="twitter:card" content="summary_large_image" />"#;
+    head += r#"<meta name="twitter:site" content="@x" />"#;
+    head += r#"<meta name="twitter:image" content="/0.jpg" />"#;
+    for index in 0..50 {
+        head += &format!(r#"<link rel="preload" href="/{index}.js" />"#);
+    }
+    head + "</head><body><p>Body</p></body>"
+}
+
+/// The HTML fixtures in `assets/`, by file name.
+fn fixtures() -> Vec<(String, String)> {
+    let mut fixtures: Vec<_> = fs::read_dir("assets")
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "html")
+        })
+        .map(|path| {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            (name, fs::read_to_string(&path).unwrap())
+        })
+        .collect();
+
+    if fixtures.is_empty() {
+        eprintln!("no HTML fixtures in assets/, benchmarking a synthetic page");
+        fixtures.push(("synthetic".into(), synthetic_page()));
+    }
+    fixtures
+}
+
+fn selector(c: &mut Criterion) {
+    let mut group = c.benchmark_group("selector");
+    group.bench_function("build", |b| b.iter(OpenGraphScraper::build_selector));
+    group.bench_function("cached", |b| b.iter(OpenGraphScraper::selector));
+    group.finish();
+}
+
+/// Scraping an already parsed document, to compare owned and borrowed output.
+fn scrape_document(c: &mut Criterion) {
+    let url = Url::parse("https://x.com/").unwrap();
+    let mut group = c.benchmark_group("scrape_document");
+    for (name, html) in fixtures() {
+        let document = Html::parse_document(OpenGraphScraper::extract_head(&html));
+        group.bench_function(format!("owned/{}", name), |b| {
+            b.iter(|| OpenGraphScraper::scrape_document(&url, black_box(&document)))
+        });
+        group.bench_function(format!("borrowed/{}", name), |b| {
+            b.iter(|| BorrowedOpenGraphObject::scrape_document(&url, black_box(&document)))
+        });
+    }
+    group.finish();
+}
+
+fn scrape(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scrape");
+    for (name, html) in fixtures() {
+        group.bench_function(format!("og/{}", name), |b| {
+            b.iter(|| OpenGraphScraper::scrape("https://x.com/", black_box(&html)))
+        });
+        group.bench_function(format!("twitter/{}", name), |b| {
+            b.iter(|| TwitterScraper::scrape("https://x.com/", black_box(&html)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, selector, scrape, scrape_document);
+criterion_main!(benches);
@@ -0,0 +1,23 @@
+fn main() {
+    #[cfg(feature = "cdylib")]
+    generate_header();
+}
+
+/// Generate the C header for the `ffi` module into `OUT_DIR`.
+///
+/// `include/metagraph.h` is checked in, and the ffi tests check it matches.
+#[cfg(feature = "cdylib")]
+fn generate_header() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", crate_dir)).unwrap();
+
+    cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_config(config)
+        .generate()
+        .expect("failed to generate the C header")
+        .write_to_file(format!("{}/metagraph.h", std::env::var("OUT_DIR").unwrap()));
+}
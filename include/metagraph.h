@@ -0,0 +1,138 @@
+#ifndef METAGRAPH_H
+#define METAGRAPH_H
+
+/* Generated by cbindgen from src/ffi.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Error codes, where 4 and up mirror `ParseError`'s variants.
+ */
+typedef enum MetagraphError {
+  METAGRAPH_ERROR_OK = 0,
+  METAGRAPH_ERROR_NULL_ARGUMENT = 1,
+  METAGRAPH_ERROR_INVALID_UTF8 = 2,
+  METAGRAPH_ERROR_INVALID_URL = 3,
+  METAGRAPH_ERROR_INVALID_PROPERTY_TAG = 4,
+  METAGRAPH_ERROR_INVALID_PROPERTY = 5,
+  METAGRAPH_ERROR_INVALID_CONTENT = 6,
+  METAGRAPH_ERROR_SERIALIZE = 7,
+  METAGRAPH_ERROR_PANIC = 8,
+} MetagraphError;
+
+/**
+ * A scraped link preview, freed with `metagraph_preview_free`.
+ */
+typedef struct MetagraphPreview MetagraphPreview;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Scrape `len` bytes of UTF-8 `html`, resolving relative links against `url`.
+ *
+ * Returns null on failure, with the reason written to `error` if it is not null.
+ *
+ * # Safety
+ *
+ * `url` must be a NUL terminated string, `html` must point to `len` bytes and
+ * `error` must be null or valid for writes.
+ */
+struct MetagraphPreview *metagraph_scrape(const char *url,
+                                          const char *html,
+                                          size_t len,
+                                          enum MetagraphError *error);
+
+/**
+ * Get the preview's title, or null if it has none.
+ *
+ * # Safety
+ *
+ * `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+ */
+const char *metagraph_preview_title(const struct MetagraphPreview *preview);
+
+/**
+ * Get the preview's description, or null if it has none.
+ *
+ * # Safety
+ *
+ * `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+ */
+const char *metagraph_preview_description(const struct MetagraphPreview *preview);
+
+/**
+ * Get the preview's canonical url, or null if it has none.
+ *
+ * # Safety
+ *
+ * `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+ */
+const char *metagraph_preview_url(const struct MetagraphPreview *preview);
+
+/**
+ * Get the preview's image url, or null if it has none.
+ *
+ * # Safety
+ *
+ * `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+ */
+const char *metagraph_preview_image(const struct MetagraphPreview *preview);
+
+/**
+ * Get the preview's site name, or null if it has none.
+ *
+ * # Safety
+ *
+ * `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+ */
+const char *metagraph_preview_site_name(const struct MetagraphPreview *preview);
+
+/**
+ * Serialize the whole preview as JSON, to be freed with `metagraph_string_free`.
+ *
+ * Returns null on failure, with the reason written to `error` if it is not null.
+ *
+ * # Safety
+ *
+ * `preview` must be null or returned by `metagraph_scrape` and not yet freed,
+ * and `error` must be null or valid for writes.
+ */
+char *metagraph_preview_json(const struct MetagraphPreview *preview, enum MetagraphError *error);
+
+/**
+ * Free a preview returned by `metagraph_scrape`. Null is ignored.
+ *
+ * # Safety
+ *
+ * `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+ */
+void metagraph_preview_free(struct MetagraphPreview *preview);
+
+/**
+ * Free a string returned by `metagraph_preview_json`. Null is ignored.
+ *
+ * # Safety
+ *
+ * `string` must be null or returned by `metagraph_preview_json` and not yet freed.
+ */
+void metagraph_string_free(char *string);
+
+/**
+ * Describe an error code, as a static string, or "unknown error" for other values.
+ *
+ * The code is taken as an `int`, since an out of range enum from C would be
+ * undefined behaviour.
+ */
+const char *metagraph_error_message(int error);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* METAGRAPH_H */
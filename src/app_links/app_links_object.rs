@@ -0,0 +1,71 @@
+use serde::Serialize;
+
+use super::property::{AndroidApp, IosApp, Web, WindowsApp};
+use crate::{
+    error::ParseError,
+    graph_object::{GraphObject, Update},
+    meta_data::MetaData,
+};
+
+/// Facebook App Links, `al:*`.
+///
+/// Each platform may list several app targets; a `url` tag starts a new target, or
+/// a `package` tag for Android.
+#[derive(Default, Debug, Serialize)]
+pub struct AppLinksObject {
+    pub ios: Option<Vec<IosApp>>,
+    pub iphone: Option<Vec<IosApp>>,
+    pub ipad: Option<Vec<IosApp>>,
+    pub android: Option<Vec<AndroidApp>>,
+    pub windows_phone: Option<Vec<WindowsApp>>,
+    pub windows: Option<Vec<WindowsApp>>,
+    pub windows_universal: Option<Vec<WindowsApp>>,
+    pub web: Option<Web>,
+}
+
+impl AppLinksObject {
+    fn update_android(&mut self, data: MetaData) -> Result<(), ParseError> {
+        let is_next = match self.android.as_ref().and_then(|apps| apps.last()) {
+            // The spec's example lists `url` before `package`, so a url may open the target.
+            Some(app) if AndroidApp::should_create_new(data.tags) => !app.package.is_empty(),
+            Some(app) => app.is_set(data.tags),
+            None => true,
+        };
+
+        if is_next {
+            let mut app = AndroidApp::default();
+            app.update_from(data)?;
+            self.android.get_or_insert_with(Vec::new).push(app);
+        } else if let Some(app) = self.android.as_mut().and_then(|apps| apps.last_mut()) {
+            app.update_from(data)?;
+        }
+        Ok(())
+    }
+}
+
+impl GraphObject for AppLinksObject {
+    fn prefix() -> &'static str {
+        "al"
+    }
+
+    fn update_from(&mut self, data: MetaData) -> Result<(), ParseError> {
+        if let Some(first_tag) = data.tags.first() {
+            match *first_tag {
+                "ios" => self.ios.extend_or_update_last(data.next())?,
+                "iphone" => self.iphone.extend_or_update_last(data.next())?,
+                "ipad" => self.ipad.extend_or_update_last(data.next())?,
+                "android" => self.update_android(data.next())?,
+                "windows_phone" => self.windows_phone.extend_or_update_last(data.next())?,
+                "windows" => self.windows.extend_or_update_last(data.next())?,
+                "windows_universal" => self.windows_universal.extend_or_update_last(data.next())?,
+                "web" => {
+                    let web = self.web.get_or_insert(Web::default());
+                    web.update_from(data.next())?;
+                }
+                _ => return Err(ParseError::InvalidPropertyTag),
+            }
+        }
+
+        Ok(())
+    }
+}
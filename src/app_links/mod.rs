@@ -0,0 +1,4 @@
+pub mod property;
+
+mod app_links_object;
+pub use app_links_object::AppLinksObject;
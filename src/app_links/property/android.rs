@@ -0,0 +1,53 @@
+use serde::Serialize;
+
+use crate::{error::ParseError, graph_object::GraphObject, meta_data::MetaData};
+
+/// An app target for `al:android`, identified by its required `package`.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct AndroidApp {
+    pub package: String,
+    pub url: Option<String>,
+    pub class: Option<String>,
+    pub app_name: Option<String>,
+}
+
+impl AndroidApp {
+    /// Whether the tag is already set, so belongs to the next target.
+    pub(crate) fn is_set(&self, tags: &[&str]) -> bool {
+        match tags {
+            ["url"] => self.url.is_some(),
+            ["class"] => self.class.is_some(),
+            ["app_name"] => self.app_name.is_some(),
+            _ => false,
+        }
+    }
+}
+
+impl GraphObject for AndroidApp {
+    fn prefix() -> &'static str {
+        "android"
+    }
+
+    fn should_create_new(tags: &[&str]) -> bool {
+        matches!(tags, ["package"])
+    }
+
+    fn update_from(&mut self, data: MetaData) -> Result<(), ParseError> {
+        match data.tags {
+            ["package"] => {
+                self.package = data.content.into();
+            }
+            ["url"] => {
+                self.url = Some(data.content.into());
+            }
+            ["class"] => {
+                self.class = Some(data.content.into());
+            }
+            ["app_name"] => {
+                self.app_name = Some(data.content.into());
+            }
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use crate::{error::ParseError, graph_object::GraphObject, meta_data::MetaData};
+
+/// An app target for `al:ios`, `al:iphone` or `al:ipad`.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct IosApp {
+    pub url: String,
+    pub app_store_id: Option<String>,
+    pub app_name: Option<String>,
+}
+
+impl GraphObject for IosApp {
+    fn prefix() -> &'static str {
+        "ios"
+    }
+
+    fn update_from(&mut self, data: MetaData) -> Result<(), ParseError> {
+        match data.tags {
+            ["url"] => {
+                self.url = data.content.into();
+            }
+            ["app_store_id"] => {
+                self.app_store_id = Some(data.content.into());
+            }
+            ["app_name"] => {
+                self.app_name = Some(data.content.into());
+            }
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+}
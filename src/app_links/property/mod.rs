@@ -0,0 +1,11 @@
+mod ios;
+pub use ios::IosApp;
+
+mod android;
+pub use android::AndroidApp;
+
+mod windows;
+pub use windows::WindowsApp;
+
+mod web;
+pub use web::Web;
@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use crate::{error::ParseError, graph_object::GraphObject, meta_data::MetaData};
+
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct Web {
+    pub url: Option<String>,
+    pub should_fallback: Option<bool>,
+}
+
+impl GraphObject for Web {
+    fn prefix() -> &'static str {
+        "web"
+    }
+
+    fn update_from(&mut self, data: MetaData) -> Result<(), ParseError> {
+        match data.tags {
+            ["url"] => {
+                self.url = Some(data.normalized_url());
+            }
+            ["should_fallback"] => {
+                let should_fallback = match data.content.to_lowercase().as_str() {
+                    "true" | "1" => true,
+                    "false" | "0" => false,
+                    _ => return Err(ParseError::InvalidContent(data.content.into())),
+                };
+                self.should_fallback = Some(should_fallback);
+            }
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use crate::{error::ParseError, graph_object::GraphObject, meta_data::MetaData};
+
+/// An app target for `al:windows`, `al:windows_phone` or `al:windows_universal`.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct WindowsApp {
+    pub url: String,
+    pub app_id: Option<String>,
+    pub app_name: Option<String>,
+}
+
+impl GraphObject for WindowsApp {
+    fn prefix() -> &'static str {
+        "windows"
+    }
+
+    fn update_from(&mut self, data: MetaData) -> Result<(), ParseError> {
+        match data.tags {
+            ["url"] => {
+                self.url = data.content.into();
+            }
+            ["app_id"] => {
+                self.app_id = Some(data.content.into());
+            }
+            ["app_name"] => {
+                self.app_name = Some(data.content.into());
+            }
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use rayon::prelude::*;
+use scraper::{Html, Selector};
+use std::{error::Error, iter};
+use url::Url;
+
+use crate::scraper::Scraper;
+
+/// Pages scraped at once per thread, bounding the pages and results held in memory.
+const PAGES_PER_THREAD: usize = 64;
+
+/// The result of scraping one page of a batch.
+pub type BatchResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+/// Scrape `(url, html)` pages in parallel, yielding a result per page in input order.
+///
+/// Pages are taken from the input and scraped a chunk at a time as results are
+/// consumed, so large inputs can be streamed. The scraper's selector is compiled
+/// once and shared by every page. Chunks are scraped on the current rayon thread
+/// pool, so consume the results inside `ThreadPool::install` to use a dedicated pool.
+pub fn scrape_batch<S, I, U, H>(pages: I) -> impl Iterator<Item = BatchResult<S::RootGraphObject>>
+where
+    S: Scraper,
+    S::RootGraphObject: Send,
+    I: IntoIterator<Item = (U, H)>,
+    U: AsRef<str> + Send,
+    H: AsRef<str> + Send,
+{
+    let selector = S::selector();
+    let mut pages = pages.into_iter();
+    let mut results = Vec::new().into_iter();
+
+    iter::from_fn(move || {
+        if let Some(result) = results.next() {
+            return Some(result);
+        }
+
+        let chunk_size = rayon::current_num_threads() * PAGES_PER_THREAD;
+        let chunk: Vec<_> = pages.by_ref().take(chunk_size).collect();
+        results = chunk
+            .into_par_iter()
+            .map(|(url, html)| scrape_page::<S>(url.as_ref(), html.as_ref(), selector))
+            .collect::<Vec<_>>()
+            .into_iter();
+        results.next()
+    })
+}
+
+fn scrape_page<S: Scraper>(
+    url: &str,
+    html: &str,
+    selector: &Selector,
+) -> BatchResult<S::RootGraphObject> {
+    // Parsing validates the supplied url.
+    let url = Url::parse(url)?;
+
+    let head = S::extract_head(html);
+    let document = Html::parse_document(head);
+
+    Ok(S::scrape_document_with(&url, &document, selector))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::OpenGraphScraper;
+
+    fn page(title: &str) -> String {
+        format!(
+            r#"<head><meta property="og:title" content="{}" /></head>"#,
+            title
+        )
+    }
+
+    #[test]
+    fn scrape_in_order() {
+        let pages = (0..100).map(|index| ("http://x.com", page(&index.to_string())));
+
+        let results: Vec<_> = scrape_batch::<OpenGraphScraper, _, _, _>(pages).collect();
+
+        assert_eq!(results.len(), 100);
+        for (index, result) in results.into_iter().enumerate() {
+            assert_eq!(result.unwrap().title, Some(index.to_string()));
+        }
+    }
+
+    #[test]
+    fn scrape_with_errors() {
+        let pages = vec![
+            ("http://x.com", page("A")),
+            ("not a url", page("B")),
+            ("http://x.com", page("C")),
+        ];
+
+        let results: Vec<_> = scrape_batch::<OpenGraphScraper, _, _, _>(pages).collect();
+
+        assert_eq!(results[0].as_ref().unwrap().title, Some("A".into()));
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap().title, Some("C".into()));
+    }
+
+    #[test]
+    fn scrape_in_pool() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap();
+
+        let results: Vec<_> = pool.install(|| {
+            scrape_batch::<OpenGraphScraper, _, _, _>([("http://x.com", page("A"))]).collect()
+        });
+
+        assert_eq!(results[0].as_ref().unwrap().title, Some("A".into()));
+    }
+
+    #[test]
+    fn scrape_in_chunks() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        let taken = AtomicUsize::new(0);
+        let pages = (0..1000).map(|index| {
+            taken.fetch_add(1, Ordering::SeqCst);
+            ("http://x.com", page(&index.to_string()))
+        });
+
+        pool.install(|| {
+            let mut results = scrape_batch::<OpenGraphScraper, _, _, _>(pages);
+            assert_eq!(results.next().unwrap().unwrap().title, Some("0".into()));
+            assert_eq!(taken.load(Ordering::SeqCst), PAGES_PER_THREAD);
+            assert_eq!(results.count(), 999);
+        });
+        assert_eq!(taken.load(Ordering::SeqCst), 1000);
+    }
+}
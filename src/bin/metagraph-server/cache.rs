@@ -0,0 +1,64 @@
+use lru::LruCache;
+use std::{
+    num::NonZeroUsize,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// A least recently used cache of response bodies, which expire after a time to live.
+pub struct Cache {
+    /// `None` when caching is disabled.
+    entries: Option<Mutex<LruCache<String, (Instant, String)>>>,
+    ttl: Duration,
+}
+
+impl Cache {
+    pub fn new(size: usize, ttl: Duration) -> Self {
+        Self {
+            entries: NonZeroUsize::new(size).map(|size| Mutex::new(LruCache::new(size))),
+            ttl,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.as_ref()?.lock().unwrap();
+
+        match entries.get(key) {
+            Some((inserted, body)) if inserted.elapsed() < self.ttl => Some(body.clone()),
+            Some(_) => {
+                entries.pop(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    pub fn insert(&self, key: String, body: String) {
+        if let Some(entries) = &self.entries {
+            entries.lock().unwrap().put(key, (Instant::now(), body));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get() {
+        let cache = Cache::new(1, Duration::from_secs(60));
+        cache.insert("a".into(), "A".into());
+        cache.insert("b".into(), "B".into());
+
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("b"), Some("B".into()));
+    }
+
+    #[test]
+    fn expire() {
+        let cache = Cache::new(1, Duration::ZERO);
+        cache.insert("a".into(), "A".into());
+
+        assert_eq!(cache.get("a"), None);
+    }
+}
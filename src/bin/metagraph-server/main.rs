@@ -0,0 +1,64 @@
+use clap::Parser;
+use std::{process::ExitCode, sync::Arc, time::Duration};
+use tiny_http::Server;
+
+mod cache;
+mod rate_limit;
+mod service;
+
+/// Serve link previews over HTTP.
+///
+/// `GET /unfurl?url=` fetches and scrapes a page, `POST /scrape?url=` scrapes
+/// the HTML body with `url` as the site url, and `GET /health` reports liveness.
+#[derive(Parser)]
+#[command(name = "metagraph-server", version)]
+struct Args {
+    /// The address to listen on.
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    addr: String,
+
+    /// The number of requests handled at once.
+    #[arg(long, default_value_t = 8)]
+    concurrency: usize,
+
+    /// Pages fetched per host per minute, or 0 for no limit.
+    #[arg(long, default_value_t = 60)]
+    rate_limit: u32,
+
+    /// The number of unfurled pages cached, or 0 to disable caching.
+    #[arg(long, default_value_t = 1024)]
+    cache_size: usize,
+
+    /// Seconds unfurled pages are cached for.
+    #[arg(long, default_value_t = 300)]
+    cache_ttl: u64,
+
+    /// Allow unfurling urls which resolve to private, loopback or link-local addresses.
+    #[arg(long)]
+    allow_private: bool,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let server = match Server::http(&args.addr) {
+        Ok(server) => server,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return ExitCode::from(2);
+        }
+    };
+    eprintln!("listening on http://{}", server.server_addr());
+
+    service::run(
+        Arc::new(server),
+        &service::Config {
+            concurrency: args.concurrency,
+            rate_limit: args.rate_limit,
+            cache_size: args.cache_size,
+            cache_ttl: Duration::from_secs(args.cache_ttl),
+            allow_private: args.allow_private,
+        },
+    );
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,80 @@
+use std::{collections::HashMap, sync::Mutex, time::Instant};
+
+/// The number of hosts tracked before idle hosts are forgotten.
+const MAX_HOSTS: usize = 10_000;
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Limits requests per host with a token bucket, refilled continuously.
+pub struct RateLimiter {
+    /// Requests allowed per host per minute, or 0 for no limit.
+    per_minute: u32,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(per_minute: u32) -> Self {
+        Self {
+            per_minute,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Take a token for the host, returning `false` if the host is over its limit.
+    pub fn check(&self, host: &str) -> bool {
+        if self.per_minute == 0 {
+            return true;
+        }
+
+        let capacity = f64::from(self.per_minute);
+        let refill = |bucket: &Bucket, now: Instant| {
+            let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+            (bucket.tokens + elapsed * capacity / 60.0).min(capacity)
+        };
+
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() >= MAX_HOSTS {
+            // A full bucket is the same as no bucket.
+            buckets.retain(|_, bucket| refill(bucket, now) < capacity);
+        }
+
+        let bucket = buckets.entry(host.into()).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+        bucket.tokens = refill(bucket, now);
+        bucket.updated = now;
+
+        if bucket.tokens < 1.0 {
+            return false;
+        }
+        bucket.tokens -= 1.0;
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let limiter = RateLimiter::new(2);
+
+        assert!(limiter.check("a.com"));
+        assert!(limiter.check("a.com"));
+        assert!(!limiter.check("a.com"));
+        assert!(limiter.check("b.com"));
+    }
+
+    #[test]
+    fn unlimited() {
+        let limiter = RateLimiter::new(0);
+
+        assert!((0..100).all(|_| limiter.check("a.com")));
+    }
+}
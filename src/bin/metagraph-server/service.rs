@@ -0,0 +1,334 @@
+use serde_json::json;
+use std::{fmt::Display, io::Read, sync::Arc, thread, time::Duration};
+use tiny_http::{Header, Method, Request, Response, Server};
+use url::{form_urlencoded, Url};
+
+use metagraph::{
+    fetch::{fetch, fetch_public, is_public_address},
+    PreviewScraper,
+};
+
+use crate::{cache::Cache, rate_limit::RateLimiter};
+
+/// The largest HTML body accepted by `POST /scrape`.
+const MAX_BODY_BYTES: u64 = 10 * 1024 * 1024;
+
+pub struct Config {
+    /// The number of requests handled at once.
+    pub concurrency: usize,
+    /// Pages fetched per host per minute, or 0 for no limit.
+    pub rate_limit: u32,
+    /// The number of unfurled pages cached, or 0 to disable caching.
+    pub cache_size: usize,
+    pub cache_ttl: Duration,
+    /// Whether to unfurl urls resolving to private, loopback or link-local addresses.
+    pub allow_private: bool,
+}
+
+struct State {
+    cache: Cache,
+    limiter: RateLimiter,
+    allow_private: bool,
+}
+
+/// An error response.
+struct Failure {
+    status: u16,
+    message: String,
+}
+
+impl Failure {
+    fn new(status: u16, message: impl Display) -> Self {
+        Self {
+            status,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Handle requests on `concurrency` worker threads until the server is dropped.
+pub fn run(server: Arc<Server>, config: &Config) {
+    let state = Arc::new(State {
+        cache: Cache::new(config.cache_size, config.cache_ttl),
+        limiter: RateLimiter::new(config.rate_limit),
+        allow_private: config.allow_private,
+    });
+
+    let workers: Vec<_> = (0..config.concurrency.max(1))
+        .map(|_| {
+            let server = server.clone();
+            let state = state.clone();
+            thread::spawn(move || {
+                for request in server.incoming_requests() {
+                    handle(&state, request);
+                }
+            })
+        })
+        .collect();
+
+    for worker in workers {
+        let _ = worker.join();
+    }
+}
+
+fn handle(state: &State, mut request: Request) {
+    let target = request.url().to_string();
+    let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+    let url = form_urlencoded::parse(query.as_bytes())
+        .find(|(key, _)| key == "url")
+        .map(|(_, url)| url.into_owned());
+
+    let result = match (request.method(), path) {
+        (Method::Get, "/health") => Ok(json!({ "status": "ok" }).to_string()),
+        (Method::Get, "/unfurl") => unfurl(state, url.as_deref()),
+        (Method::Post, "/scrape") => read_body(request.as_reader(), MAX_BODY_BYTES)
+            .and_then(|html| scrape(url.as_deref(), &html)),
+        (_, "/health" | "/unfurl" | "/scrape") => Err(Failure::new(405, "method not allowed")),
+        _ => Err(Failure::new(404, "not found")),
+    };
+
+    let (status, body) = match result {
+        Ok(body) => (200, body),
+        Err(failure) => (
+            failure.status,
+            json!({ "error": failure.message }).to_string(),
+        ),
+    };
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let _ = request.respond(
+        Response::from_string(body)
+            .with_status_code(status)
+            .with_header(content_type),
+    );
+}
+
+/// Fetch and scrape a page, or return the cached preview.
+fn unfurl(state: &State, url: Option<&str>) -> Result<String, Failure> {
+    let url = url.ok_or_else(|| Failure::new(400, "missing url parameter"))?;
+    let url = Url::parse(url).map_err(|error| Failure::new(400, error))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(Failure::new(400, "unsupported url scheme"));
+    }
+    if !state.allow_private {
+        // Fetching also checks, after every redirect, but this gives a clearer error.
+        let addresses = url
+            .socket_addrs(|| None)
+            .map_err(|error| Failure::new(502, error))?;
+        if addresses
+            .iter()
+            .any(|address| !is_public_address(address.ip()))
+        {
+            return Err(Failure::new(403, "url resolves to a non-public address"));
+        }
+    }
+
+    if let Some(body) = state.cache.get(url.as_str()) {
+        return Ok(body);
+    }
+
+    let host = url.host_str().unwrap_or_default();
+    if !state.limiter.check(host) {
+        return Err(Failure::new(
+            429,
+            format!("rate limit exceeded for {}", host),
+        ));
+    }
+
+    let fetched = match state.allow_private {
+        true => fetch(url.as_str()),
+        false => fetch_public(url.as_str()),
+    }
+    .map_err(|error| Failure::new(502, error))?;
+    let body = preview(&fetched.url, &fetched.html)?;
+
+    state.cache.insert(url.into(), body.clone());
+    Ok(body)
+}
+
+/// Read a request body, refusing bodies over `limit` rather than scraping them truncated.
+fn read_body(body: impl Read, limit: u64) -> Result<String, Failure> {
+    let mut bytes = vec![];
+    body.take(limit + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|error| Failure::new(400, error))?;
+    if bytes.len() as u64 > limit {
+        return Err(Failure::new(413, format!("body is over {} bytes", limit)));
+    }
+    String::from_utf8(bytes).map_err(|error| Failure::new(400, error))
+}
+
+/// Scrape posted HTML, resolving relative links against the `url` parameter.
+fn scrape(url: Option<&str>, html: &str) -> Result<String, Failure> {
+    let url = url.ok_or_else(|| Failure::new(400, "missing url parameter"))?;
+    preview(url, html)
+}
+
+fn preview(url: &str, html: &str) -> Result<String, Failure> {
+    let preview = PreviewScraper::scrape(url, html).map_err(|error| Failure::new(400, error))?;
+    serde_json::to_string(&preview).map_err(|error| Failure::new(500, error))
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::Value;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    const HTML: &str = r#"<head>
+        <meta property="og:title" content="Fixture" />
+        <meta property="og:image" content="/a.jpg" />
+    </head>"#;
+
+    /// Serve `HTML` for every request, counting the requests.
+    fn fixture_server() -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", server.server_addr());
+        let hits = Arc::new(AtomicUsize::new(0));
+
+        let counter = hits.clone();
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let _ = request.respond(Response::from_string(HTML));
+            }
+        });
+        (address, hits)
+    }
+
+    fn start(config: Config) -> String {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", server.server_addr());
+
+        thread::spawn(move || run(Arc::new(server), &config));
+        address
+    }
+
+    fn config() -> Config {
+        Config {
+            concurrency: 2,
+            rate_limit: 0,
+            cache_size: 16,
+            cache_ttl: Duration::from_secs(60),
+            // The fixture server listens on loopback.
+            allow_private: true,
+        }
+    }
+
+    fn call(request: ureq::Request, body: Option<&str>) -> (u16, Value) {
+        let result = match body {
+            Some(body) => request.send_string(body),
+            None => request.call(),
+        };
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(error) => panic!("{}", error),
+        };
+        let status = response.status();
+        (
+            status,
+            serde_json::from_reader(response.into_reader()).unwrap(),
+        )
+    }
+
+    fn unfurl(server: &str, url: &str) -> (u16, Value) {
+        call(
+            ureq::get(&format!("{}/unfurl", server)).query("url", url),
+            None,
+        )
+    }
+
+    #[test]
+    fn health() {
+        let server = start(config());
+
+        let (status, body) = call(ureq::get(&format!("{}/health", server)), None);
+        assert_eq!(status, 200);
+        assert_eq!(body["status"], "ok");
+    }
+
+    #[test]
+    fn unfurl_cached() {
+        let (fixture, hits) = fixture_server();
+        let server = start(config());
+        let page = format!("{}/page", fixture);
+
+        for _ in 0..2 {
+            let (status, body) = unfurl(&server, &page);
+            assert_eq!(status, 200);
+            assert_eq!(body["title"], "Fixture");
+            assert_eq!(body["image"], format!("{}/a.jpg", fixture));
+        }
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn unfurl_rate_limited() {
+        let (fixture, hits) = fixture_server();
+        let server = start(Config {
+            rate_limit: 1,
+            cache_size: 0,
+            ..config()
+        });
+        let page = format!("{}/page", fixture);
+
+        assert_eq!(unfurl(&server, &page).0, 200);
+        assert_eq!(unfurl(&server, &page).0, 429);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn unfurl_invalid() {
+        let server = start(config());
+
+        assert_eq!(unfurl(&server, "not a url").0, 400);
+        assert_eq!(unfurl(&server, "file:///etc/hosts").0, 400);
+        assert_eq!(call(ureq::get(&format!("{}/unfurl", server)), None).0, 400);
+    }
+
+    #[test]
+    fn unfurl_private() {
+        let (fixture, hits) = fixture_server();
+        let server = start(Config {
+            allow_private: false,
+            ..config()
+        });
+
+        for url in [
+            format!("{}/page", fixture),
+            "http://169.254.169.254/latest/meta-data/".into(),
+            "http://10.0.0.1/".into(),
+            "http://[::1]/".into(),
+        ] {
+            let (status, body) = unfurl(&server, &url);
+            assert_eq!(status, 403, "{}", url);
+            assert_eq!(body["error"], "url resolves to a non-public address");
+        }
+        assert_eq!(hits.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn scrape() {
+        let server = start(config());
+
+        let request = ureq::post(&format!("{}/scrape", server)).query("url", "http://x.com");
+        let (status, body) = call(request, Some(HTML));
+        assert_eq!(status, 200);
+        assert_eq!(body["title"], "Fixture");
+        assert_eq!(body["open_graph"]["images"][0]["url"], "http://x.com/a.jpg");
+
+        let (status, _) = call(ureq::get(&format!("{}/scrape", server)), None);
+        assert_eq!(status, 405);
+    }
+
+    #[test]
+    fn body_limit() {
+        assert_eq!(
+            read_body(&b"<p></p>"[..], 7).ok().as_deref(),
+            Some("<p></p>")
+        );
+        assert_eq!(read_body(&b"<p></p>!"[..], 7).unwrap_err().status, 413);
+        assert_eq!(read_body(&b"caf\xe9"[..], 7).unwrap_err().status, 400);
+    }
+}
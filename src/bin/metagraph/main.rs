@@ -0,0 +1,294 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::{Map, Value};
+use std::{
+    error::Error,
+    fs,
+    io::{self, Read},
+    path::Path,
+    process::ExitCode,
+};
+
+use metagraph::{
+    capture::CapturedPage,
+    fetch::fetch,
+    validation::{Validate, ValidationError},
+    warc::WarcReader,
+    AppLinksScraper, FacebookScraper, OpenGraphScraper, ProductScraper, Scraper, TwitterScraper,
+};
+
+mod render;
+
+/// Scrape Open Graph, Twitter and related metadata from HTML files, stdin or URLs.
+#[derive(Parser)]
+#[command(
+    name = "metagraph",
+    version,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// HTML files, HAR or MHTML captures, `-` for stdin, or http(s) URLs.
+    #[arg(required = true)]
+    inputs: Vec<String>,
+
+    /// The URL relative links in file or stdin input are resolved against, overriding captures.
+    #[arg(long)]
+    base_url: Option<String>,
+
+    /// The vocabularies to scrape.
+    #[arg(long, value_enum, value_delimiter = ',', default_values = ["og", "twitter"])]
+    vocab: Vec<Vocab>,
+
+    #[arg(long, value_enum, default_value = "pretty")]
+    format: Format,
+
+    /// Exit with a non-zero status if the Open Graph or Twitter metadata is invalid.
+    #[arg(long)]
+    validate: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Scrape the HTML responses in WARC files, printing a line of JSON per page.
+    Warc {
+        /// WARC files, optionally gzip compressed per record.
+        #[arg(required = true)]
+        files: Vec<String>,
+
+        /// The vocabularies to scrape.
+        #[arg(long, value_enum, value_delimiter = ',', default_values = ["og", "twitter"])]
+        vocab: Vec<Vocab>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum Vocab {
+    Og,
+    Twitter,
+    Al,
+    Fb,
+    Product,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+enum Format {
+    /// Indented JSON.
+    Pretty,
+    /// One line of JSON per input.
+    Json,
+    /// Aligned property and content columns.
+    Table,
+    /// HTML meta tags.
+    Meta,
+}
+
+impl Vocab {
+    fn name(&self) -> &'static str {
+        match self {
+            Vocab::Og => "og",
+            Vocab::Twitter => "twitter",
+            Vocab::Al => "al",
+            Vocab::Fb => "fb",
+            Vocab::Product => "product",
+        }
+    }
+
+    /// Scrape the vocabulary, collecting any validation errors.
+    fn scrape(
+        &self,
+        url: &str,
+        html: &str,
+        errors: &mut Vec<ValidationError>,
+    ) -> Result<Value, Box<dyn Error>> {
+        let value = match self {
+            Vocab::Og => {
+                let result = OpenGraphScraper::scrape(url, html)?;
+                errors.extend(result.validate());
+                serde_json::to_value(result)?
+            }
+            Vocab::Twitter => {
+                let result = TwitterScraper::scrape(url, html)?;
+                errors.extend(result.validate());
+                serde_json::to_value(result)?
+            }
+            Vocab::Al => serde_json::to_value(AppLinksScraper::scrape(url, html)?)?,
+            Vocab::Fb => serde_json::to_value(FacebookScraper::scrape(url, html)?)?,
+            Vocab::Product => serde_json::to_value(ProductScraper::scrape(url, html)?)?,
+        };
+        Ok(value)
+    }
+}
+
+/// Read an input, returning its site url and HTML.
+fn read_input(input: &str, base_url: Option<&str>) -> Result<(String, String), Box<dyn Error>> {
+    if input.starts_with("http://") || input.starts_with("https://") {
+        let fetched = fetch(input).map_err(|error| error.to_string())?;
+        return Ok((fetched.url, fetched.html));
+    }
+
+    // Captures record the url the page was served from.
+    let extension = Path::new(input)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default();
+    let is_extension = |names: &[&str]| {
+        names
+            .iter()
+            .any(|name| extension.eq_ignore_ascii_case(name))
+    };
+    let captured = if is_extension(&["har"]) {
+        Some(CapturedPage::from_har(&fs::read_to_string(input)?)?)
+    } else if is_extension(&["mht", "mhtml"]) {
+        Some(CapturedPage::from_mhtml(&fs::read(input)?)?)
+    } else {
+        None
+    };
+    if let Some(page) = captured {
+        let url = base_url.map_or(page.url, str::to_string);
+        return Ok((url, page.html));
+    }
+
+    let html = if input == "-" {
+        let mut html = String::new();
+        io::stdin().read_to_string(&mut html)?;
+        html
+    } else {
+        fs::read_to_string(input)?
+    };
+
+    let url = match base_url {
+        Some(base_url) => base_url.to_string(),
+        None if input == "-" => {
+            eprintln!("warning: stdin has no --base-url, so relative URLs are left unresolved");
+            "about:blank".to_string()
+        }
+        None => {
+            let path = fs::canonicalize(Path::new(input))?;
+            url::Url::from_file_path(&path)
+                .map_err(|_| format!("invalid file path, {}", path.display()))?
+                .to_string()
+        }
+    };
+    Ok((url, html))
+}
+
+/// Scrape each input, reporting inputs which can't be read or scraped on stderr.
+///
+/// Exits with 2 if any input failed, else 1 if any failed validation.
+fn run(args: &Args) -> Result<ExitCode, Box<dyn Error>> {
+    let mut is_ok = true;
+    let mut is_valid = true;
+
+    'inputs: for input in &args.inputs {
+        let (url, html) = match read_input(input, args.base_url.as_deref()) {
+            Ok(page) => page,
+            Err(error) => {
+                eprintln!("{}: {}", input, error);
+                is_ok = false;
+                continue;
+            }
+        };
+
+        let mut errors = vec![];
+        let mut result = Map::new();
+        for vocab in &args.vocab {
+            match vocab.scrape(&url, &html, &mut errors) {
+                Ok(value) => result.insert(vocab.name().into(), value),
+                Err(error) => {
+                    eprintln!("{}: {}", input, error);
+                    is_ok = false;
+                    continue 'inputs;
+                }
+            };
+        }
+
+        if args.inputs.len() > 1 && matches!(args.format, Format::Table | Format::Meta) {
+            println!("==> {} <==", input);
+        }
+
+        match args.format {
+            Format::Pretty => println!("{}", serde_json::to_string_pretty(&result)?),
+            Format::Json => println!("{}", serde_json::to_string(&result)?),
+            Format::Table => print!("{}", render::table(&result)),
+            Format::Meta => print!("{}", render::meta_tags(&result)),
+        }
+
+        if args.validate {
+            for error in &errors {
+                eprintln!("{}: {}", input, error);
+            }
+            is_valid &= errors.is_empty();
+        }
+    }
+
+    Ok(match (is_ok, is_valid) {
+        (false, _) => ExitCode::from(2),
+        (true, false) => ExitCode::from(1),
+        (true, true) => ExitCode::SUCCESS,
+    })
+}
+
+/// Scrape each page in the WARC files, reporting pages which fail on stderr.
+fn run_warc(files: &[String], vocab: &[Vocab]) -> Result<bool, Box<dyn Error>> {
+    let mut is_ok = true;
+
+    for file in files {
+        let reader = match WarcReader::open(file) {
+            Ok(reader) => reader,
+            Err(error) => {
+                eprintln!("{}: {}", file, error);
+                is_ok = false;
+                continue;
+            }
+        };
+        'pages: for page in reader.pages() {
+            // The reader stops after an unreadable record, so keep going until then.
+            let page = match page {
+                Ok(page) => page,
+                Err(error) => {
+                    eprintln!("{}: {}", file, error);
+                    is_ok = false;
+                    continue;
+                }
+            };
+
+            let mut result = Map::new();
+            result.insert("url".into(), page.target_uri.clone().into());
+            for vocab in vocab {
+                match vocab.scrape(&page.target_uri, &page.html, &mut vec![]) {
+                    Ok(value) => result.insert(vocab.name().into(), value),
+                    Err(error) => {
+                        eprintln!("{}: {}: {}", file, page.target_uri, error);
+                        is_ok = false;
+                        continue 'pages;
+                    }
+                };
+            }
+            println!("{}", serde_json::to_string(&result)?);
+        }
+    }
+
+    Ok(is_ok)
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let result = match &args.command {
+        Some(Command::Warc { files, vocab }) => run_warc(files, vocab).map(|is_ok| match is_ok {
+            true => ExitCode::SUCCESS,
+            false => ExitCode::from(1),
+        }),
+        None => run(&args),
+    };
+    match result {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use serde_json::{Map, Value};
+
+/// Field names which differ from their tag names.
+fn tag_name(field: &str) -> &str {
+    match field {
+        "images" => "image",
+        "prices" => "price",
+        "media_type" => "type",
+        "stream_content_type" => "stream:content_type",
+        field => field,
+    }
+}
+
+/// Fields holding the value of the parent tag itself, e.g. `url` of `og:image`.
+const PRIMARY_FIELDS: &[&str] = &["url", "username", "locale"];
+
+fn flatten(path: &str, value: &Value, has_primary: bool, tags: &mut Vec<(String, String)>) {
+    match value {
+        Value::Null => {}
+        Value::String(content) => tags.push((path.into(), content.clone())),
+        Value::Bool(_) | Value::Number(_) => tags.push((path.into(), value.to_string())),
+        Value::Array(values) => {
+            for value in values {
+                flatten(path, value, has_primary, tags);
+            }
+        }
+        Value::Object(object) => {
+            let primary = PRIMARY_FIELDS
+                .iter()
+                .find(|field| has_primary && object.contains_key(**field));
+
+            if let Some(field) = primary {
+                flatten(path, &object[*field], has_primary, tags);
+            }
+            for (field, value) in object {
+                if Some(&field.as_str()) != primary {
+                    let path = format!("{}:{}", path, tag_name(field));
+                    flatten(&path, value, has_primary, tags);
+                }
+            }
+        }
+    }
+}
+
+/// Flatten Twitter's numbered and platform suffixed tags, which nest differently.
+fn flatten_twitter(object: &Map<String, Value>, tags: &mut Vec<(String, String)>) {
+    let mut object = object.clone();
+
+    if let Some(Value::Array(gallery)) = object.remove("gallery") {
+        for (index, image) in gallery.iter().enumerate() {
+            flatten(&format!("twitter:image{}", index), image, true, tags);
+        }
+    }
+    if let Some(Value::Array(labels)) = object.remove("labels") {
+        for (index, label) in labels.iter().enumerate() {
+            flatten(
+                &format!("twitter:label{}", index + 1),
+                &label["label"],
+                true,
+                tags,
+            );
+            flatten(
+                &format!("twitter:data{}", index + 1),
+                &label["data"],
+                true,
+                tags,
+            );
+        }
+    }
+    if let Some(Value::Object(app)) = object.remove("app") {
+        for (platform, fields) in &app {
+            match fields {
+                Value::Object(fields) => {
+                    for (field, value) in fields {
+                        let path = format!("twitter:app:{}:{}", field, platform);
+                        flatten(&path, value, true, tags);
+                    }
+                }
+                value => flatten(&format!("twitter:app:{}", platform), value, true, tags),
+            }
+        }
+    }
+
+    flatten("twitter", &Value::Object(object), true, tags);
+}
+
+/// Flatten scraped results back into `(attribute, property, content)` tags.
+fn tags(result: &Map<String, Value>) -> Vec<(&'static str, String, String)> {
+    let mut all_tags = vec![];
+
+    for (vocab, value) in result {
+        let mut tags = vec![];
+        let attribute = match (vocab.as_str(), value) {
+            ("twitter", Value::Object(object)) => {
+                flatten_twitter(object, &mut tags);
+                "name"
+            }
+            ("og", _) => {
+                flatten(vocab, value, true, &mut tags);
+                "property"
+            }
+            _ => {
+                flatten(vocab, value, false, &mut tags);
+                "property"
+            }
+        };
+
+        for (property, content) in tags {
+            // Determiners serialize as variant names, e.g. `An` and `Blank`.
+            let content = match property.as_str() {
+                "og:determiner" if content == "Blank" => String::new(),
+                "og:determiner" => content.to_lowercase(),
+                _ => content,
+            };
+            all_tags.push((attribute, property, content));
+        }
+    }
+    all_tags
+}
+
+fn escape(content: &str) -> String {
+    content
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Render the results as HTML meta tags.
+pub fn meta_tags(result: &Map<String, Value>) -> String {
+    tags(result)
+        .into_iter()
+        .map(|(attribute, property, content)| {
+            format!(
+                "<meta {}=\"{}\" content=\"{}\" />\n",
+                attribute,
+                escape(&property),
+                escape(&content)
+            )
+        })
+        .collect()
+}
+
+/// Render the results as aligned property and content columns.
+pub fn table(result: &Map<String, Value>) -> String {
+    let tags = tags(result);
+    let width = tags
+        .iter()
+        .map(|(_, property, _)| property.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    tags.into_iter()
+        .map(|(_, property, content)| format!("{:width$}  {}\n", property, content))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn result(value: Value) -> Map<String, Value> {
+        value.as_object().unwrap().clone()
+    }
+
+    #[test]
+    fn render_open_graph() {
+        let result = result(json!({
+            "og": {
+                "title": "A \"title\"",
+                "determiner": "Blank",
+                "images": [{"url": "http://x.com/a.jpg", "width": 10, "alt": null}],
+            }
+        }));
+
+        assert_eq!(
+            meta_tags(&result),
+            concat!(
+                "<meta property=\"og:determiner\" content=\"\" />\n",
+                "<meta property=\"og:image\" content=\"http://x.com/a.jpg\" />\n",
+                "<meta property=\"og:image:width\" content=\"10\" />\n",
+                "<meta property=\"og:title\" content=\"A &quot;title&quot;\" />\n",
+            )
+        );
+    }
+
+    #[test]
+    fn render_twitter() {
+        let result = result(json!({
+            "twitter": {
+                "card": "app",
+                "app": {"country": "GB", "iphone": {"id": "1", "name": null}},
+                "labels": [{"label": "Author", "data": "X"}, {"label": null, "data": null}],
+            }
+        }));
+
+        assert_eq!(
+            table(&result),
+            concat!(
+                "twitter:label1         Author\n",
+                "twitter:data1          X\n",
+                "twitter:app:country    GB\n",
+                "twitter:app:id:iphone  1\n",
+                "twitter:card           app\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::Deserialize;
+use std::error::Error;
+use url::Url;
+
+use super::page::CapturedPage;
+use crate::content_type::{decode, is_html};
+
+/// The most redirects followed from the first request to the main document.
+const MAX_REDIRECTS: usize = 20;
+
+#[derive(Deserialize)]
+struct Har {
+    log: Log,
+}
+
+#[derive(Deserialize)]
+struct Log {
+    #[serde(default)]
+    pages: Vec<Page>,
+    entries: Vec<Entry>,
+}
+
+#[derive(Deserialize)]
+struct Page {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct Entry {
+    pageref: Option<String>,
+    request: Request,
+    response: Response,
+}
+
+#[derive(Deserialize)]
+struct Request {
+    url: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Response {
+    status: i64,
+    #[serde(default, rename = "redirectURL")]
+    redirect_url: String,
+    #[serde(default)]
+    headers: Vec<Header>,
+    content: Content,
+}
+
+#[derive(Deserialize)]
+struct Header {
+    name: String,
+    value: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Content {
+    #[serde(default)]
+    mime_type: String,
+    text: Option<String>,
+    encoding: Option<String>,
+}
+
+impl Entry {
+    /// The url this entry redirects to, if it is a redirect.
+    fn redirect(&self) -> Result<Option<Url>, Box<dyn Error>> {
+        let response = &self.response;
+        if !(300..400).contains(&response.status) {
+            return Ok(None);
+        }
+
+        let location = match response.redirect_url.as_str() {
+            "" => response
+                .headers
+                .iter()
+                .find(|header| header.name.eq_ignore_ascii_case("Location"))
+                .map(|header| header.value.as_str()),
+            location => Some(location),
+        };
+        match location {
+            Some(location) => Ok(Some(Url::parse(&self.request.url)?.join(location)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl CapturedPage {
+    /// Get the main document of the first page in a HAR capture.
+    ///
+    /// Redirects from the page's first request are followed, so the site url is the
+    /// final url, and base64 encoded bodies are decoded with their charset.
+    pub fn from_har(json: &str) -> Result<Self, Box<dyn Error>> {
+        let har: Har = serde_json::from_str(json)?;
+        let entries = har.log.entries;
+        // Captures without pages, e.g. from proxies, take the first request.
+        let page_id = har.log.pages.first().map(|page| page.id.as_str());
+
+        let mut entry = entries
+            .iter()
+            .find(|entry| page_id.is_none() || entry.pageref.as_deref() == page_id)
+            .ok_or("HAR has no entries")?;
+        for _ in 0..MAX_REDIRECTS {
+            let Some(location) = entry.redirect()? else {
+                break;
+            };
+            entry = entries
+                .iter()
+                .find(|entry| Url::parse(&entry.request.url).ok().as_ref() == Some(&location))
+                .ok_or_else(|| format!("redirect to {} was not captured", location))?;
+        }
+        if entry.redirect()?.is_some() {
+            return Err("too many redirects".into());
+        }
+
+        let content = &entry.response.content;
+        if !is_html(&content.mime_type) {
+            return Err(format!("main document is not HTML, {}", content.mime_type).into());
+        }
+        let text = content
+            .text
+            .as_deref()
+            .ok_or("main document has no captured content")?;
+        let html = match content.encoding.as_deref() {
+            Some("base64") => decode(&STANDARD.decode(text)?, &content.mime_type).into_owned(),
+            _ => text.to_string(),
+        };
+
+        Ok(Self {
+            url: entry.request.url.clone(),
+            html,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    fn entry(
+        url: &str,
+        status: i64,
+        redirect: &str,
+        content: serde_json::Value,
+    ) -> serde_json::Value {
+        json!({
+            "pageref": "page_1",
+            "request": { "method": "GET", "url": url },
+            "response": { "status": status, "redirectURL": redirect, "headers": [], "content": content },
+        })
+    }
+
+    #[test]
+    fn from_har() {
+        let har = json!({ "log": {
+            "pages": [{ "id": "page_1" }],
+            "entries": [
+                entry("http://x.com/", 301, "https://x.com/", json!({ "mimeType": "", "size": 0 })),
+                entry("https://x.com/", 302, "/home", json!({ "mimeType": "", "size": 0 })),
+                entry("https://x.com/a.css", 200, "", json!({ "mimeType": "text/css", "text": "" })),
+                entry("https://x.com/home", 200, "", json!({
+                    "mimeType": "text/html; charset=ISO-8859-1",
+                    "text": STANDARD.encode(b"<title>caf\xe9</title>"),
+                    "encoding": "base64",
+                })),
+            ],
+        }});
+
+        let page = CapturedPage::from_har(&har.to_string()).unwrap();
+
+        assert_eq!(page.url, "https://x.com/home");
+        assert_eq!(page.html, "<title>café</title>");
+    }
+
+    #[test]
+    fn from_har_location_header() {
+        let mut redirect = entry("http://x.com/", 302, "", json!({ "mimeType": "" }));
+        redirect["response"]["headers"] = json!([{ "name": "location", "value": "/b" }]);
+        let har = json!({ "log": { "entries": [
+            redirect,
+            entry("http://x.com/b", 200, "", json!({ "mimeType": "text/html", "text": "<p>b</p>" })),
+        ]}});
+
+        let page = CapturedPage::from_har(&har.to_string()).unwrap();
+
+        assert_eq!(page.url, "http://x.com/b");
+        assert_eq!(page.html, "<p>b</p>");
+    }
+
+    #[test]
+    fn from_har_invalid() {
+        let uncaptured = json!({ "log": { "entries": [
+            entry("http://x.com/", 302, "/b", json!({ "mimeType": "" })),
+        ]}});
+        let not_html = json!({ "log": { "entries": [
+            entry("http://x.com/", 200, "", json!({ "mimeType": "image/png", "text": "" })),
+        ]}});
+
+        assert!(CapturedPage::from_har(&uncaptured.to_string()).is_err());
+        assert!(CapturedPage::from_har(&not_html.to_string()).is_err());
+        assert!(CapturedPage::from_har(r#"{ "log": { "entries": [] } }"#).is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::error::Error;
+
+use super::page::CapturedPage;
+use crate::content_type::{decode, find, find_header, is_html, media_type, parameter, split_head};
+
+/// MIME headers and the body following them.
+struct Part<'a> {
+    headers: Vec<(String, String)>,
+    body: &'a [u8],
+}
+
+impl<'a> Part<'a> {
+    fn parse(input: &'a [u8]) -> Self {
+        let (head, body) = split_head(input);
+
+        let mut headers: Vec<(String, String)> = vec![];
+        for line in String::from_utf8_lossy(head).lines() {
+            // Folded lines continue the previous header.
+            if line.starts_with([' ', '\t']) {
+                if let Some((_, value)) = headers.last_mut() {
+                    value.push(' ');
+                    value.push_str(line.trim());
+                }
+            } else if let Some((name, value)) = line.split_once(':') {
+                headers.push((name.trim().to_string(), value.trim().to_string()));
+            }
+        }
+
+        Self { headers, body }
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    fn content_type(&self) -> &str {
+        self.header("Content-Type").unwrap_or("text/plain")
+    }
+
+    /// The body with its `Content-Transfer-Encoding` removed.
+    fn decoded_body(&self) -> Result<Vec<u8>, Box<dyn Error>> {
+        let encoding = self.header("Content-Transfer-Encoding").unwrap_or("binary");
+        if encoding.eq_ignore_ascii_case("quoted-printable") {
+            Ok(decode_quoted_printable(self.body))
+        } else if encoding.eq_ignore_ascii_case("base64") {
+            let body: Vec<_> = self
+                .body
+                .iter()
+                .copied()
+                .filter(|byte| !byte.is_ascii_whitespace())
+                .collect();
+            Ok(STANDARD.decode(body)?)
+        } else {
+            Ok(self.body.to_vec())
+        }
+    }
+}
+
+fn decode_quoted_printable(input: &[u8]) -> Vec<u8> {
+    let hex = |byte: u8| (byte as char).to_digit(16).map(|digit| digit as u8);
+
+    let mut decoded = Vec::with_capacity(input.len());
+    let mut index = 0;
+    while index < input.len() {
+        if input[index] == b'=' {
+            match input.get(index + 1..).unwrap_or_default() {
+                // Soft line breaks join lines split for length.
+                [b'\n', ..] => {
+                    index += 2;
+                    continue;
+                }
+                [b'\r', b'\n', ..] => {
+                    index += 3;
+                    continue;
+                }
+                [high, low, ..] => {
+                    if let (Some(high), Some(low)) = (hex(*high), hex(*low)) {
+                        decoded.push(high << 4 | low);
+                        index += 3;
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+        }
+        decoded.push(input[index]);
+        index += 1;
+    }
+    decoded
+}
+
+/// Split a multipart body on its boundary, dropping the preamble and epilogue.
+fn split_parts<'a>(body: &'a [u8], boundary: &str) -> Vec<&'a [u8]> {
+    let delimiter = format!("--{}", boundary);
+    let delimiter = delimiter.as_bytes();
+
+    let mut parts = vec![];
+    let Some(start) = find(body, delimiter) else {
+        return parts;
+    };
+    let mut rest = &body[start + delimiter.len()..];
+    // The closing delimiter is followed by `--`.
+    while !rest.starts_with(b"--") {
+        let line_end = find(rest, b"\n").map_or(rest.len(), |end| end + 1);
+        rest = &rest[line_end..];
+
+        let end = find(rest, delimiter).unwrap_or(rest.len());
+        let part = &rest[..end];
+        let part = part
+            .strip_suffix(b"\r\n")
+            .or_else(|| part.strip_suffix(b"\n"))
+            .unwrap_or(part);
+        parts.push(part);
+
+        if end == rest.len() {
+            break;
+        }
+        rest = &rest[end + delimiter.len()..];
+    }
+    parts
+}
+
+impl CapturedPage {
+    /// Get the main document of an MHTML saved page, i.e. a `.mht` or `.mhtml` file.
+    ///
+    /// The main document is the part named by the `start` parameter, or else the
+    /// first HTML part, and its `Content-Location` is used as the site url.
+    pub fn from_mhtml(mhtml: &[u8]) -> Result<Self, Box<dyn Error>> {
+        let message = Part::parse(mhtml);
+        let content_type = message.content_type().to_string();
+        // Chrome records the page url on the message.
+        let snapshot_url = message
+            .header("Snapshot-Content-Location")
+            .map(str::to_string);
+
+        let parts = match parameter(&content_type, "boundary") {
+            Some(boundary) if media_type(&content_type).starts_with("multipart/") => {
+                split_parts(message.body, boundary)
+                    .into_iter()
+                    .map(Part::parse)
+                    .collect()
+            }
+            _ => vec![message],
+        };
+
+        let start = parameter(&content_type, "start");
+        let document = match start {
+            Some(start) => parts
+                .iter()
+                .find(|part| part.header("Content-ID") == Some(start)),
+            None => parts.iter().find(|part| is_html(part.content_type())),
+        }
+        .ok_or("MHTML has no HTML document")?;
+        if !is_html(document.content_type()) {
+            return Err(format!("main document is not HTML, {}", document.content_type()).into());
+        }
+
+        let url = document
+            .header("Content-Location")
+            .or(snapshot_url.as_deref())
+            .ok_or("main document has no Content-Location")?
+            .to_string();
+        let html = decode(&document.decoded_body()?, document.content_type()).into_owned();
+        Ok(Self { url, html })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MHTML: &str = "From: <Saved by Blink>\r
+Snapshot-Content-Location: http://x.com/page\r
+Subject: Page\r
+MIME-Version: 1.0\r
+Content-Type: multipart/related;\r
+\ttype=\"text/html\";\r
+\tboundary=\"----MultipartBoundary--abc----\"\r
+\r
+\r
+------MultipartBoundary--abc----\r
+Content-Type: text/html\r
+Content-ID: <frame-1@mhtml.blink>\r
+Content-Transfer-Encoding: quoted-printable\r
+Content-Location: http://x.com/page\r
+\r
+<html><head><meta charset=3D\"utf-8\"><meta property=3D\"og:title\" content=3D\"=\r
+Caf=C3=A9\" /></head></html>\r
+------MultipartBoundary--abc----\r
+Content-Type: text/css\r
+Content-Transfer-Encoding: quoted-printable\r
+Content-Location: http://x.com/a.css\r
+\r
+p { color: red; }\r
+------MultipartBoundary--abc------\r
+";
+
+    #[test]
+    fn quoted_printable() {
+        assert_eq!(
+            decode_quoted_printable(b"a=3Db=\r\nc=\nd=zz="),
+            b"a=bcd=zz="
+        );
+    }
+
+    #[test]
+    fn from_mhtml() {
+        let page = CapturedPage::from_mhtml(MHTML.as_bytes()).unwrap();
+
+        assert_eq!(page.url, "http://x.com/page");
+        assert_eq!(
+            page.html,
+            r#"<html><head><meta charset="utf-8"><meta property="og:title" content="Café" /></head></html>"#
+        );
+    }
+
+    #[test]
+    fn from_mhtml_start() {
+        let mhtml = "Content-Type: multipart/related; boundary=b; start=\"<main>\"\n\n--b
+Content-Type: text/html\nContent-Location: http://x.com/frame\n\n<p>frame</p>
+--b
+Content-Type: text/html; charset=ISO-8859-1\nContent-ID: <main>
+Content-Transfer-Encoding: base64\nContent-Location: http://x.com/\n
+PHA+Y2Fm6TwvcD4=
+--b--\n";
+
+        let page = CapturedPage::from_mhtml(mhtml.as_bytes()).unwrap();
+
+        assert_eq!(page.url, "http://x.com/");
+        assert_eq!(page.html, "<p>café</p>");
+    }
+
+    #[test]
+    fn from_mhtml_invalid() {
+        let no_html = "Content-Type: multipart/related; boundary=b\n\n--b
+Content-Type: text/css\nContent-Location: http://x.com/a.css\n\np {}\n--b--\n";
+
+        assert!(CapturedPage::from_mhtml(no_html.as_bytes()).is_err());
+        assert!(CapturedPage::from_mhtml(b"Content-Type: text/html\n\n<p></p>").is_err());
+    }
+}
@@ -0,0 +1,6 @@
+mod har;
+
+mod mhtml;
+
+mod page;
+pub use page::CapturedPage;
@@ -0,0 +1,35 @@
+use std::error::Error;
+
+use crate::scraper::Scraper;
+
+/// The main document of a HAR capture or MHTML saved page, for reproducing a scrape exactly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CapturedPage {
+    /// The url the document was served from, used as the site url.
+    pub url: String,
+    pub html: String,
+}
+
+impl CapturedPage {
+    pub fn scrape<S: Scraper>(&self) -> Result<S::RootGraphObject, Box<dyn Error>> {
+        S::scrape(&self.url, &self.html)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OpenGraphScraper;
+
+    #[test]
+    fn scrape() {
+        let page = CapturedPage {
+            url: "http://x.com/a/".into(),
+            html: r#"<head><meta property="og:image" content="b.jpg" /></head>"#.into(),
+        };
+
+        let result = page.scrape::<OpenGraphScraper>().unwrap();
+
+        assert_eq!(result.images.unwrap()[0].url, "http://x.com/a/b.jpg");
+    }
+}
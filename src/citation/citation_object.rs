@@ -0,0 +1,230 @@
+use scraper::{Html, Selector};
+use serde::Serialize;
+use std::{error::Error, str::FromStr, sync::LazyLock};
+use url::Url;
+
+use super::property::{normalize_doi, PublicationDate};
+use crate::{error::ParseError, meta_data::MetaData};
+
+/// Bibliographic metadata from Google Scholar `citation_*` and Dublin Core tags.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct Citation {
+    pub title: Option<String>,
+    /// Authors in the order they appear in the document.
+    pub authors: Option<Vec<String>>,
+    pub doi: Option<String>,
+    pub pdf_url: Option<String>,
+    pub journal_title: Option<String>,
+    pub publisher: Option<String>,
+    pub publication_date: Option<PublicationDate>,
+    pub volume: Option<String>,
+    pub issue: Option<String>,
+    pub first_page: Option<String>,
+    pub last_page: Option<String>,
+}
+
+impl Citation {
+    /// Update from a Google Scholar tag, e.g. `author` from `citation_author`.
+    fn update_from_citation(&mut self, name: &str, data: MetaData) -> Result<(), ParseError> {
+        match name {
+            "title" => {
+                self.title = Some(data.content.into());
+            }
+            "author" => {
+                let authors = self.authors.get_or_insert_with(Vec::new);
+                authors.push(data.content.into());
+            }
+            "doi" => {
+                self.doi = Some(normalize_doi(data.content)?);
+            }
+            "pdf_url" => {
+                self.pdf_url = Some(data.normalized_url());
+            }
+            "journal_title" => {
+                self.journal_title = Some(data.content.into());
+            }
+            "publisher" => {
+                self.publisher = Some(data.content.into());
+            }
+            "publication_date" | "date" => {
+                self.publication_date = Some(PublicationDate::from_str(data.content)?);
+            }
+            "volume" => {
+                self.volume = Some(data.content.into());
+            }
+            "issue" => {
+                self.issue = Some(data.content.into());
+            }
+            "firstpage" => {
+                self.first_page = Some(data.content.into());
+            }
+            "lastpage" => {
+                self.last_page = Some(data.content.into());
+            }
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+
+    /// Update from a Dublin Core element or term, e.g. `creator` from `DC.creator`.
+    fn update_from_dublin_core(&mut self, name: &str, data: MetaData) -> Result<(), ParseError> {
+        match name.to_ascii_lowercase().as_str() {
+            "title" => {
+                self.title = Some(data.content.into());
+            }
+            "creator" => {
+                let authors = self.authors.get_or_insert_with(Vec::new);
+                authors.push(data.content.into());
+            }
+            "identifier" => {
+                self.doi = Some(normalize_doi(data.content)?);
+            }
+            "publisher" => {
+                self.publisher = Some(data.content.into());
+            }
+            "issued" | "date" => {
+                self.publication_date = Some(PublicationDate::from_str(data.content)?);
+            }
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+
+    /// Fill any fields missing from `self` with those of `other`.
+    fn or(self, other: Self) -> Self {
+        Self {
+            title: self.title.or(other.title),
+            authors: self.authors.or(other.authors),
+            doi: self.doi.or(other.doi),
+            pdf_url: self.pdf_url.or(other.pdf_url),
+            journal_title: self.journal_title.or(other.journal_title),
+            publisher: self.publisher.or(other.publisher),
+            publication_date: self.publication_date.or(other.publication_date),
+            volume: self.volume.or(other.volume),
+            issue: self.issue.or(other.issue),
+            first_page: self.first_page.or(other.first_page),
+            last_page: self.last_page.or(other.last_page),
+        }
+    }
+}
+
+pub struct CitationScraper {}
+
+impl CitationScraper {
+    /// Get the CSS selector for named meta elements.
+    fn selector() -> &'static Selector {
+        static SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("head > meta[name]").unwrap());
+        &SELECTOR
+    }
+
+    /// Scrape the document for a citation.
+    ///
+    /// Google Scholar tags take precedence, with Dublin Core filling the gaps.
+    pub fn scrape(url: &str, html: &str) -> Result<Citation, Box<dyn Error>> {
+        // Parsing validates the supplied url.
+        let url = Url::parse(url)?;
+
+        let document = Html::parse_document(html);
+        let selector = Self::selector();
+
+        let mut citation = Citation::default();
+        let mut dublin_core = Citation::default();
+
+        for element in document.select(selector) {
+            let name = element.value().attr("name");
+            let content = element.value().attr("content");
+
+            if let (Some(name), Some(content)) = (name, content) {
+                let meta_data = MetaData {
+                    site_url: &url,
+                    tags: &[],
+                    content: content.trim(),
+                };
+
+                if let Some(name) = name.strip_prefix("citation_") {
+                    let _ = citation.update_from_citation(name, meta_data);
+                } else if let Some((vocabulary, name)) = name.split_once('.') {
+                    if vocabulary.eq_ignore_ascii_case("dc")
+                        || vocabulary.eq_ignore_ascii_case("dcterms")
+                    {
+                        let _ = dublin_core.update_from_dublin_core(name, meta_data);
+                    }
+                }
+            }
+        }
+
+        Ok(citation.or(dublin_core))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Helper to scrape HTML string.
+    fn scrape(html: &str) -> Citation {
+        CitationScraper::scrape("http://x.com", html).unwrap()
+    }
+
+    #[test]
+    fn scrape_citation() {
+        let result = scrape(
+            r#"<head>
+                <meta name="citation_title" content="X" />
+                <meta name="citation_author" content="B" />
+                <meta name="citation_author" content="A" />
+                <meta name="citation_doi" content="doi:10.1000/XYZ" />
+                <meta name="citation_pdf_url" content="./x.pdf" />
+                <meta name="citation_journal_title" content="Journal" />
+                <meta name="citation_publication_date" content="2010/05/21" />
+            </head>"#,
+        );
+        assert_eq!(result.title, Some("X".into()));
+        assert_eq!(result.authors, Some(vec!["B".into(), "A".into()]));
+        assert_eq!(result.doi, Some("10.1000/xyz".into()));
+        assert_eq!(result.pdf_url, Some("http://x.com/x.pdf".into()));
+        assert_eq!(result.journal_title, Some("Journal".into()));
+        assert_eq!(
+            result.publication_date.map(|date| date.to_string()),
+            Some("2010-05-21".into())
+        );
+    }
+
+    #[test]
+    fn scrape_dublin_core() {
+        let result = scrape(
+            r#"<head>
+                <meta name="DC.title" content="X" />
+                <meta name="dc.creator" content="A" />
+                <meta name="DCTERMS.issued" content="2010" />
+            </head>"#,
+        );
+        assert_eq!(result.title, Some("X".into()));
+        assert_eq!(result.authors, Some(vec!["A".into()]));
+        assert_eq!(result.publication_date.unwrap().year, 2010);
+    }
+
+    #[test]
+    fn scrape_citation_precedence() {
+        let result = scrape(
+            r#"<head>
+                <meta name="citation_title" content="X" />
+                <meta name="DC.title" content="Y" />
+                <meta name="DC.creator" content="A" />
+            </head>"#,
+        );
+        assert_eq!(result.title, Some("X".into()));
+        assert_eq!(result.authors, Some(vec!["A".into()]));
+    }
+
+    #[test]
+    fn scrape_invalid_doi() {
+        let result = scrape(
+            r#"<head>
+                <meta name="citation_doi" content="X" />
+            </head>"#,
+        );
+        assert_eq!(result.doi, None);
+    }
+}
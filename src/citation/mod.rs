@@ -0,0 +1,4 @@
+pub mod property;
+
+mod citation_object;
+pub use citation_object::{Citation, CitationScraper};
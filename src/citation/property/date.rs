@@ -0,0 +1,116 @@
+use serde::{Serialize, Serializer};
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+use crate::error::ParseError;
+
+/// A publication date, which is often only given to the year or month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublicationDate {
+    pub year: u16,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl FromStr for PublicationDate {
+    type Err = ParseError;
+
+    /// Parse `YYYY`, `YYYY-MM` or `YYYY-MM-DD`, separated by `-` or `/`.
+    ///
+    /// The month and day may have one digit, as in Google Scholar's `2010/5/12`.
+    ///
+    /// Any time following the date, e.g. `2020-01-02T10:00:00Z`, is ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidContent(s.into());
+
+        let date = s.trim().split(['T', ' ']).next().unwrap_or_default();
+        let mut parts = date.split(['-', '/']);
+
+        let mut number = |digits: RangeInclusive<usize>| -> Result<Option<u16>, ParseError> {
+            match parts.next() {
+                None => Ok(None),
+                Some(part)
+                    if digits.contains(&part.len()) && part.bytes().all(|b| b.is_ascii_digit()) =>
+                {
+                    Ok(Some(part.parse().map_err(|_| invalid())?))
+                }
+                Some(_) => Err(invalid()),
+            }
+        };
+
+        let year = number(4..=4)?.ok_or_else(invalid)?;
+        let month = number(1..=2)?.map(|month| month as u8);
+        let day = number(1..=2)?.map(|day| day as u8);
+
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        if month.is_some_and(|month| !(1..=12).contains(&month)) {
+            return Err(invalid());
+        }
+        if let (Some(month), Some(day)) = (month, day) {
+            if !(1..=days_in_month(year, month)).contains(&day) {
+                return Err(invalid());
+            }
+        }
+
+        Ok(Self { year, month, day })
+    }
+}
+
+impl Display for PublicationDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for PublicationDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn date_from_str() {
+        let date = PublicationDate::from_str("2010/05/21").unwrap();
+        assert_eq!(date.to_string(), "2010-05-21");
+
+        let date = PublicationDate::from_str("2010/5/12").unwrap();
+        assert_eq!(date.to_string(), "2010-05-12");
+
+        let date = PublicationDate::from_str("2010-05").unwrap();
+        assert_eq!((date.month, date.day), (Some(5), None));
+
+        assert!(PublicationDate::from_str("2020-02-29T10:00:00Z").is_ok());
+        assert!(PublicationDate::from_str("2021-02-29").is_err());
+        assert!(PublicationDate::from_str("2021-13").is_err());
+        assert!(PublicationDate::from_str("21-01-01").is_err());
+        assert!(PublicationDate::from_str("2021-001-01").is_err());
+        assert!(PublicationDate::from_str("May 2021").is_err());
+    }
+}
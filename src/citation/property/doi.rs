@@ -0,0 +1,56 @@
+use crate::error::ParseError;
+
+/// Resolver and scheme prefixes which may precede a DOI.
+const DOI_PREFIXES: &[&str] = &[
+    "https://doi.org/",
+    "http://doi.org/",
+    "https://dx.doi.org/",
+    "http://dx.doi.org/",
+    "doi:",
+];
+
+/// Normalize a DOI to its bare, lowercase form, e.g. `10.1000/xyz123`.
+///
+/// DOIs are case insensitive, and are often published as resolver urls.
+pub fn normalize_doi(content: &str) -> Result<String, ParseError> {
+    let mut doi = content.trim();
+
+    for prefix in DOI_PREFIXES {
+        // Slicing by the prefix length could split a multibyte character.
+        let has_prefix = doi
+            .get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix));
+        if has_prefix {
+            doi = doi[prefix.len()..].trim_start();
+            break;
+        }
+    }
+
+    let is_valid = doi
+        .split_once('/')
+        .is_some_and(|(registrant, suffix)| registrant.starts_with("10.") && !suffix.is_empty());
+
+    if !is_valid {
+        return Err(ParseError::InvalidContent(content.into()));
+    }
+    Ok(doi.to_lowercase())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn doi_normalized() {
+        assert_eq!(normalize_doi("10.1000/XYZ").unwrap(), "10.1000/xyz");
+        assert_eq!(normalize_doi("doi: 10.1000/xyz").unwrap(), "10.1000/xyz");
+        assert_eq!(
+            normalize_doi("https://doi.org/10.1000/xyz").unwrap(),
+            "10.1000/xyz"
+        );
+        assert!(normalize_doi("10.1000").is_err());
+        assert!(normalize_doi("https://x.com/10.1000/xyz").is_err());
+        assert!(normalize_doi("日本語のタイトル").is_err());
+        assert!(normalize_doi("do日本").is_err());
+    }
+}
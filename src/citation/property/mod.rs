@@ -0,0 +1,5 @@
+mod date;
+pub use date::PublicationDate;
+
+mod doi;
+pub use doi::normalize_doi;
@@ -0,0 +1,86 @@
+// `fetch` alone only decodes bodies.
+#![cfg_attr(not(any(feature = "warc", feature = "capture")), allow(dead_code))]
+
+use encoding_rs::{Encoding, UTF_8};
+use std::borrow::Cow;
+
+pub(crate) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Split headers from the body following the first blank line, with either line ending.
+pub(crate) fn split_head(input: &[u8]) -> (&[u8], &[u8]) {
+    match find(input, b"\r\n\r\n") {
+        Some(end) => (&input[..end], &input[end + 4..]),
+        None => match find(input, b"\n\n") {
+            Some(end) => (&input[..end], &input[end + 2..]),
+            None => (input, &[][..]),
+        },
+    }
+}
+
+/// Get a header's value by case insensitive name.
+pub(crate) fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// The media type of a `Content-Type` value, e.g. `text/html`.
+pub(crate) fn media_type(content_type: &str) -> &str {
+    content_type.split(';').next().unwrap_or_default().trim()
+}
+
+/// A parameter of a `Content-Type` value, e.g. its `charset`.
+pub(crate) fn parameter<'a>(content_type: &'a str, name: &str) -> Option<&'a str> {
+    content_type
+        .split(';')
+        .skip(1)
+        .filter_map(|parameter| parameter.split_once('='))
+        .find(|(key, _)| key.trim().eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.trim().trim_matches('"'))
+}
+
+pub(crate) fn is_html(content_type: &str) -> bool {
+    let media_type = media_type(content_type);
+    media_type.eq_ignore_ascii_case("text/html")
+        || media_type.eq_ignore_ascii_case("application/xhtml+xml")
+}
+
+/// Decode text with the charset of a `Content-Type` value, defaulting to UTF-8 and preferring a BOM.
+pub(crate) fn decode<'a>(bytes: &'a [u8], content_type: &str) -> Cow<'a, str> {
+    let encoding = parameter(content_type, "charset")
+        .and_then(|charset| Encoding::for_label(charset.as_bytes()))
+        .unwrap_or(UTF_8);
+    encoding.decode(bytes).0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn content_type() {
+        let content_type = r#"text/html; charset="ISO-8859-1""#;
+
+        assert!(is_html(content_type));
+        assert!(is_html("Application/XHTML+XML"));
+        assert!(!is_html("text/css"));
+        assert_eq!(parameter(content_type, "Charset"), Some("ISO-8859-1"));
+        assert_eq!(decode(b"caf\xe9", content_type), "café");
+        assert_eq!(decode("café".as_bytes(), "text/html"), "café");
+    }
+
+    #[test]
+    fn head() {
+        assert_eq!(
+            split_head(b"a: b\r\n\r\nbody"),
+            (&b"a: b"[..], &b"body"[..])
+        );
+        assert_eq!(split_head(b"a: b\n\nbody"), (&b"a: b"[..], &b"body"[..]));
+        assert_eq!(split_head(b"a: b"), (&b"a: b"[..], &b""[..]));
+    }
+}
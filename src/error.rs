@@ -20,3 +20,19 @@ impl Display for ParseError {
         }
     }
 }
+
+impl std::error::Error for ParseError {}
+
+/// A meta property which could not be applied to the scraped object.
+#[derive(Debug)]
+pub struct Diagnostic {
+    pub property: String,
+    pub content: String,
+    pub error: ParseError,
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.error, self.property)
+    }
+}
@@ -0,0 +1,86 @@
+use serde::Serialize;
+
+use crate::{error::ParseError, graph_object::GraphObject, meta_data::MetaData};
+
+/// Facebook namespace properties, `fb:*`, and the `article:publisher` page.
+#[derive(Default, Debug, Serialize)]
+pub struct FacebookObject {
+    pub app_id: Option<String>,
+    pub pages: Option<Vec<String>>,
+    pub admins: Option<Vec<String>>,
+    pub profile_id: Option<String>,
+    pub publisher: Option<String>,
+}
+
+/// Append the ids from a comma separated list, which may also be repeated.
+fn extend_ids(ids: &mut Option<Vec<String>>, content: &str) {
+    let ids = ids.get_or_insert_with(Vec::new);
+    ids.extend(
+        content
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(String::from),
+    );
+}
+
+impl GraphObject for FacebookObject {
+    fn prefix() -> &'static str {
+        "fb"
+    }
+
+    fn update_from(&mut self, data: MetaData) -> Result<(), ParseError> {
+        match data.tags {
+            ["app_id"] => {
+                self.app_id = Some(data.content.into());
+            }
+            ["pages"] => {
+                extend_ids(&mut self.pages, data.content);
+            }
+            ["admins"] => {
+                extend_ids(&mut self.admins, data.content);
+            }
+            ["profile_id"] => {
+                self.profile_id = Some(data.content.into());
+            }
+            // Scraped from `article:publisher`, see `FacebookScraper`.
+            ["publisher"] => {
+                self.publisher = Some(data.normalized_url());
+            }
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use url::Url;
+
+    use super::*;
+
+    #[test]
+    fn update_ids() {
+        let mut graph_object = FacebookObject::default();
+        let site_url = Url::parse("http://x.com").unwrap();
+
+        graph_object
+            .update_from(MetaData {
+                site_url: &site_url,
+                tags: &["pages"],
+                content: "1, 2,,",
+            })
+            .unwrap();
+        graph_object
+            .update_from(MetaData {
+                site_url: &site_url,
+                tags: &["pages"],
+                content: "3",
+            })
+            .unwrap();
+        assert_eq!(
+            graph_object.pages,
+            Some(vec!["1".into(), "2".into(), "3".into()])
+        );
+    }
+}
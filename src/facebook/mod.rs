@@ -0,0 +1,2 @@
+mod facebook_object;
+pub use facebook_object::FacebookObject;
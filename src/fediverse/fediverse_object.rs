@@ -0,0 +1,140 @@
+use scraper::{ElementRef, Html, Selector};
+use serde::Serialize;
+use std::{error::Error, str::FromStr, sync::LazyLock};
+use url::Url;
+
+use super::property::Handle;
+use crate::meta_data::MetaData;
+
+/// Fediverse authorship, from `fediverse:creator` and ActivityPub links.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct FediverseObject {
+    /// Handles from `<meta name="fediverse:creator">`, in document order.
+    pub creators: Option<Vec<Handle>>,
+    /// Profile urls from `<link rel="me">`.
+    pub me: Option<Vec<String>>,
+    /// ActivityPub actor urls from `<link rel="alternate">`.
+    pub actors: Option<Vec<String>>,
+}
+
+/// Whether a link type is an ActivityStreams document.
+fn is_activity_type(media_type: &str) -> bool {
+    let media_type = media_type.to_ascii_lowercase();
+    media_type.starts_with("application/activity+json")
+        || (media_type.starts_with("application/ld+json")
+            && media_type.contains("https://www.w3.org/ns/activitystreams"))
+}
+
+pub struct FediverseScraper {}
+
+impl FediverseScraper {
+    /// Get the CSS selector for creator meta elements.
+    fn creator_selector() -> &'static Selector {
+        static SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse(r#"head > meta[name="fediverse:creator"]"#).unwrap());
+        &SELECTOR
+    }
+
+    /// Get the CSS selector for link elements with a relation to an account.
+    fn link_selector() -> &'static Selector {
+        static SELECTOR: LazyLock<Selector> = LazyLock::new(|| {
+            Selector::parse(r#"link[rel~="me"][href], link[rel~="alternate"][href]"#).unwrap()
+        });
+        &SELECTOR
+    }
+
+    /// Resolve the href of a link against the site url.
+    fn get_href(url: &Url, element: &ElementRef) -> Option<String> {
+        let content = element.value().attr("href")?;
+        let meta_data = MetaData {
+            site_url: url,
+            tags: &[],
+            content: content.trim(),
+        };
+        Some(meta_data.normalized_url())
+    }
+
+    /// Scrape the document for fediverse metadata.
+    pub fn scrape(url: &str, html: &str) -> Result<FediverseObject, Box<dyn Error>> {
+        // Parsing validates the supplied url.
+        let url = Url::parse(url)?;
+
+        let document = Html::parse_document(html);
+        let mut result = FediverseObject::default();
+
+        for element in document.select(Self::creator_selector()) {
+            let handle = element
+                .value()
+                .attr("content")
+                .and_then(|content| Handle::from_str(content).ok());
+
+            if let Some(handle) = handle {
+                result.creators.get_or_insert_with(Vec::new).push(handle);
+            }
+        }
+
+        for element in document.select(Self::link_selector()) {
+            let Some(href) = Self::get_href(&url, &element) else {
+                continue;
+            };
+            let rel = element.value().attr("rel").unwrap_or_default();
+            let has_rel = |name: &str| {
+                rel.split_whitespace()
+                    .any(|rel| rel.eq_ignore_ascii_case(name))
+            };
+            let media_type = element.value().attr("type").unwrap_or_default();
+
+            if has_rel("me") {
+                result.me.get_or_insert_with(Vec::new).push(href.clone());
+            }
+            if has_rel("alternate") && is_activity_type(media_type) {
+                result.actors.get_or_insert_with(Vec::new).push(href);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Helper to scrape HTML string.
+    fn scrape(html: &str) -> FediverseObject {
+        FediverseScraper::scrape("http://x.com", html).unwrap()
+    }
+
+    #[test]
+    fn scrape_creators() {
+        let result = scrape(
+            r#"<head>
+                <meta name="fediverse:creator" content="@a@x.social" />
+                <meta name="fediverse:creator" content="invalid" />
+                <meta name="fediverse:creator" content="@b@y.social" />
+            </head>"#,
+        );
+        let creators = result.creators.unwrap();
+        assert_eq!(creators.len(), 2);
+        assert_eq!(creators[1].user, "b");
+        assert_eq!(creators[1].domain, "y.social");
+    }
+
+    #[test]
+    fn scrape_links() {
+        let result = scrape(
+            r#"<head>
+                <link rel="me" href="https://x.social/@a" />
+                <link rel="alternate" type="application/activity+json" href="/users/a" />
+                <link rel="alternate" type="application/rss+xml" href="/feed" />
+            </head>"#,
+        );
+        assert_eq!(result.me, Some(vec!["https://x.social/@a".into()]));
+        assert_eq!(result.actors, Some(vec!["http://x.com/users/a".into()]));
+    }
+
+    #[test]
+    fn scrape_none() {
+        assert_eq!(scrape("<head></head>"), FediverseObject::default());
+    }
+}
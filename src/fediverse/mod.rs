@@ -0,0 +1,4 @@
+pub mod property;
+
+mod fediverse_object;
+pub use fediverse_object::{FediverseObject, FediverseScraper};
@@ -0,0 +1,62 @@
+use serde::Serialize;
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::error::ParseError;
+
+/// A fediverse account handle, `@user@domain`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Handle {
+    pub user: String,
+    pub domain: String,
+}
+
+impl FromStr for Handle {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidContent(s.into());
+
+        let handle = s.trim();
+        let handle = handle.strip_prefix('@').unwrap_or(handle);
+        let (user, domain) = handle.split_once('@').ok_or_else(invalid)?;
+
+        let is_valid = |part: &str| {
+            !part.is_empty() && !part.contains(|c: char| c == '@' || c.is_whitespace())
+        };
+        if !is_valid(user) || !is_valid(domain) {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            user: user.into(),
+            domain: domain.to_lowercase(),
+        })
+    }
+}
+
+impl Display for Handle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "@{}@{}", self.user, self.domain)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn handle_from_str() {
+        let handle = Handle::from_str("@user@Mastodon.Social").unwrap();
+        assert_eq!(handle.user, "user");
+        assert_eq!(handle.domain, "mastodon.social");
+        assert_eq!(handle.to_string(), "@user@mastodon.social");
+
+        assert!(Handle::from_str("user@x.com").is_ok());
+        assert!(Handle::from_str("@user").is_err());
+        assert!(Handle::from_str("@user@").is_err());
+        assert!(Handle::from_str("@a@b@c").is_err());
+    }
+}
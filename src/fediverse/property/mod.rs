@@ -0,0 +1,2 @@
+mod handle;
+pub use handle::Handle;
@@ -0,0 +1,192 @@
+use std::{
+    error::Error,
+    io::{self, Read},
+    net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs},
+    sync::LazyLock,
+};
+use ureq::{Agent, AgentBuilder};
+
+use crate::content_type::decode;
+
+/// The largest response body read, to bound memory on unexpected downloads.
+const MAX_BODY_BYTES: u64 = 10 * 1024 * 1024;
+
+const USER_AGENT: &str = concat!("metagraph/", env!("CARGO_PKG_VERSION"));
+
+/// An agent which refuses to connect to non-public addresses, checked on every redirect.
+static PUBLIC_AGENT: LazyLock<Agent> =
+    LazyLock::new(|| AgentBuilder::new().resolver(resolve_public).build());
+
+/// A fetched page.
+#[derive(Debug)]
+pub struct Fetched {
+    /// The final url after redirects, to use as the site url when scraping.
+    pub url: String,
+    pub html: String,
+}
+
+/// Whether an address is reachable on the public internet, i.e. not private,
+/// loopback, link-local or otherwise reserved.
+pub fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            !(ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_multicast()
+                || first == 0
+                // Carrier-grade NAT, 100.64.0.0/10.
+                || (first == 100 && (64..128).contains(&second))
+                // Benchmarking, 198.18.0.0/15.
+                || (first == 198 && (18..20).contains(&second))
+                // Reserved, 240.0.0.0/4, including broadcast.
+                || first >= 240)
+        }
+        IpAddr::V6(ip) => {
+            let octets = ip.octets();
+            let embedded = |at: usize| {
+                is_public_address(
+                    Ipv4Addr::from([octets[at], octets[at + 1], octets[at + 2], octets[at + 3]])
+                        .into(),
+                )
+            };
+            match ip.segments() {
+                // IPv4-mapped `::ffff:0:0/96`, NAT64 `64:ff9b::/96` and the deprecated
+                // IPv4-compatible `::/96`, which also covers `::` and `::1`.
+                [0, 0, 0, 0, 0, 0xffff | 0, ..] | [0x64, 0xff9b, 0, 0, 0, 0, ..] => embedded(12),
+                // 6to4, `2002::/16`, routes to the IPv4 address after the prefix.
+                [0x2002, ..] => embedded(2),
+                // Local-use NAT64, `64:ff9b:1::/48`, translates to private networks.
+                [0x64, 0xff9b, 1, ..] => false,
+                [first, ..] => {
+                    !(ip.is_multicast()
+                        // Unique local, fc00::/7, and link-local, fe80::/10.
+                        || (first & 0xfe00) == 0xfc00
+                        || (first & 0xffc0) == 0xfe80)
+                }
+            }
+        }
+    }
+}
+
+/// Resolve a `host:port`, failing if any address is not public.
+fn resolve_public(netloc: &str) -> io::Result<Vec<SocketAddr>> {
+    let addresses: Vec<_> = netloc.to_socket_addrs()?.collect();
+    if addresses
+        .iter()
+        .any(|address| !is_public_address(address.ip()))
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} resolves to a non-public address", netloc),
+        ));
+    }
+    Ok(addresses)
+}
+
+/// Fetch a page over HTTP, following redirects.
+pub fn fetch(url: &str) -> Result<Fetched, Box<dyn Error + Send + Sync>> {
+    fetch_with(&ureq::agent(), url)
+}
+
+/// Fetch a page like `fetch`, refusing any host, including redirect targets, which
+/// resolves to a private, loopback or link-local address.
+pub fn fetch_public(url: &str) -> Result<Fetched, Box<dyn Error + Send + Sync>> {
+    fetch_with(&PUBLIC_AGENT, url)
+}
+
+fn fetch_with(agent: &Agent, url: &str) -> Result<Fetched, Box<dyn Error + Send + Sync>> {
+    let response = agent.get(url).set("User-Agent", USER_AGENT).call()?;
+    let final_url = response.get_url().to_string();
+    let content_type = response
+        .header("Content-Type")
+        .unwrap_or_default()
+        .to_string();
+
+    let mut bytes = vec![];
+    response
+        .into_reader()
+        .take(MAX_BODY_BYTES)
+        .read_to_end(&mut bytes)?;
+
+    Ok(Fetched {
+        url: final_url,
+        html: decode(&bytes, &content_type).into_owned(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::{io::Write, net::TcpListener, thread};
+
+    use super::*;
+
+    #[test]
+    fn public_address() {
+        for ip in [
+            "93.184.216.34",
+            "2606:2800:220:1::1",
+            "::ffff:93.184.216.34",
+            "64:ff9b::93.184.216.34",
+            "2002:5db8:d822::1",
+        ] {
+            assert!(is_public_address(ip.parse().unwrap()), "{}", ip);
+        }
+        for ip in [
+            "127.0.0.1",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::",
+            "::ffff:127.0.0.1",
+            "::127.0.0.1",
+            "::10.0.0.1",
+            "64:ff9b::10.0.0.1",
+            "64:ff9b::7f00:1",
+            "64:ff9b:1::5db8:d822",
+            "2002:7f00:1::1",
+            "2002:a9fe:a9fe::",
+            "198.18.0.1",
+            "198.19.255.255",
+            "240.0.0.1",
+            "255.255.255.255",
+        ] {
+            assert!(!is_public_address(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[test]
+    fn fetch_charset() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.read(&mut [0; 1024]);
+            stream
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=windows-1252\r\n\
+                      Content-Length: 4\r\n\r\ncaf\xe9",
+                )
+                .unwrap();
+        });
+
+        let fetched = fetch(&format!("http://{}/", address)).unwrap();
+        assert_eq!(fetched.html, "café");
+    }
+
+    #[test]
+    fn fetch_public_refuses_loopback() {
+        let error = fetch_public("http://127.0.0.1:1/").unwrap_err();
+        assert!(error.to_string().contains("non-public"), "{}", error);
+    }
+}
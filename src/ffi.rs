@@ -0,0 +1,322 @@
+//! A C API for embedding, declared in `include/metagraph.h`.
+//!
+//! Strings returned by accessors are owned by the preview and live until it is
+//! freed, while strings returned by `metagraph_preview_json` are owned by the
+//! caller and freed with `metagraph_string_free`. Panics are caught at every
+//! entry point rather than unwinding into the caller.
+
+use std::{
+    ffi::{c_char, c_int, CStr, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr, slice, str,
+};
+
+use crate::{preview::Preview, ParseError, PreviewScraper};
+
+/// Error codes, where 4 and up mirror `ParseError`'s variants.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetagraphError {
+    Ok = 0,
+    NullArgument = 1,
+    InvalidUtf8 = 2,
+    InvalidUrl = 3,
+    InvalidPropertyTag = 4,
+    InvalidProperty = 5,
+    InvalidContent = 6,
+    Serialize = 7,
+    Panic = 8,
+}
+
+impl MetagraphError {
+    const ALL: [Self; 9] = [
+        Self::Ok,
+        Self::NullArgument,
+        Self::InvalidUtf8,
+        Self::InvalidUrl,
+        Self::InvalidPropertyTag,
+        Self::InvalidProperty,
+        Self::InvalidContent,
+        Self::Serialize,
+        Self::Panic,
+    ];
+
+    fn from_code(code: c_int) -> Option<Self> {
+        Self::ALL.into_iter().find(|error| *error as c_int == code)
+    }
+}
+
+impl From<&ParseError> for MetagraphError {
+    fn from(error: &ParseError) -> Self {
+        match error {
+            ParseError::InvalidPropertyTag => Self::InvalidPropertyTag,
+            ParseError::InvalidProperty(_) => Self::InvalidProperty,
+            ParseError::InvalidContent(_) => Self::InvalidContent,
+        }
+    }
+}
+
+/// A scraped link preview, freed with `metagraph_preview_free`.
+pub struct MetagraphPreview {
+    preview: Preview,
+    title: Option<CString>,
+    description: Option<CString>,
+    url: Option<CString>,
+    image: Option<CString>,
+    site_name: Option<CString>,
+}
+
+/// Convert text for C, dropping any NULs so that present text is never null.
+fn to_c_string(text: &Option<String>) -> Option<CString> {
+    text.as_deref()
+        .and_then(|text| CString::new(text.replace('\0', "")).ok())
+}
+
+/// Call `body`, returning `fallback` if it panics.
+fn guard<T>(fallback: T, body: impl FnOnce() -> T) -> T {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or(fallback)
+}
+
+impl From<Preview> for MetagraphPreview {
+    fn from(preview: Preview) -> Self {
+        Self {
+            title: to_c_string(&preview.title),
+            description: to_c_string(&preview.description),
+            url: to_c_string(&preview.url),
+            image: to_c_string(&preview.image),
+            site_name: to_c_string(&preview.site_name),
+            preview,
+        }
+    }
+}
+
+unsafe fn set_error(error: *mut MetagraphError, code: MetagraphError) {
+    if !error.is_null() {
+        *error = code;
+    }
+}
+
+unsafe fn scrape(
+    url: *const c_char,
+    html: *const c_char,
+    len: usize,
+) -> Result<MetagraphPreview, MetagraphError> {
+    if url.is_null() || (html.is_null() && len > 0) {
+        return Err(MetagraphError::NullArgument);
+    }
+
+    let url = CStr::from_ptr(url)
+        .to_str()
+        .map_err(|_| MetagraphError::InvalidUtf8)?;
+    let html = match len {
+        0 => "",
+        _ => str::from_utf8(slice::from_raw_parts(html.cast(), len))
+            .map_err(|_| MetagraphError::InvalidUtf8)?,
+    };
+
+    let preview = PreviewScraper::scrape(url, html).map_err(|error| {
+        match error.downcast_ref::<ParseError>() {
+            Some(error) => error.into(),
+            None => MetagraphError::InvalidUrl,
+        }
+    })?;
+    Ok(preview.into())
+}
+
+/// Scrape `len` bytes of UTF-8 `html`, resolving relative links against `url`.
+///
+/// Returns null on failure, with the reason written to `error` if it is not null.
+///
+/// # Safety
+///
+/// `url` must be a NUL terminated string, `html` must point to `len` bytes and
+/// `error` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn metagraph_scrape(
+    url: *const c_char,
+    html: *const c_char,
+    len: usize,
+    error: *mut MetagraphError,
+) -> *mut MetagraphPreview {
+    match guard(Err(MetagraphError::Panic), || scrape(url, html, len)) {
+        Ok(preview) => {
+            set_error(error, MetagraphError::Ok);
+            Box::into_raw(Box::new(preview))
+        }
+        Err(code) => {
+            set_error(error, code);
+            ptr::null_mut()
+        }
+    }
+}
+
+unsafe fn field(
+    preview: *const MetagraphPreview,
+    field: fn(&MetagraphPreview) -> &Option<CString>,
+) -> *const c_char {
+    guard(ptr::null(), || {
+        match preview.as_ref().and_then(|preview| field(preview).as_ref()) {
+            Some(text) => text.as_ptr(),
+            None => ptr::null(),
+        }
+    })
+}
+
+/// Get the preview's title, or null if it has none.
+///
+/// # Safety
+///
+/// `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn metagraph_preview_title(
+    preview: *const MetagraphPreview,
+) -> *const c_char {
+    field(preview, |preview| &preview.title)
+}
+
+/// Get the preview's description, or null if it has none.
+///
+/// # Safety
+///
+/// `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn metagraph_preview_description(
+    preview: *const MetagraphPreview,
+) -> *const c_char {
+    field(preview, |preview| &preview.description)
+}
+
+/// Get the preview's canonical url, or null if it has none.
+///
+/// # Safety
+///
+/// `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn metagraph_preview_url(preview: *const MetagraphPreview) -> *const c_char {
+    field(preview, |preview| &preview.url)
+}
+
+/// Get the preview's image url, or null if it has none.
+///
+/// # Safety
+///
+/// `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn metagraph_preview_image(
+    preview: *const MetagraphPreview,
+) -> *const c_char {
+    field(preview, |preview| &preview.image)
+}
+
+/// Get the preview's site name, or null if it has none.
+///
+/// # Safety
+///
+/// `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn metagraph_preview_site_name(
+    preview: *const MetagraphPreview,
+) -> *const c_char {
+    field(preview, |preview| &preview.site_name)
+}
+
+/// Serialize the whole preview as JSON, to be freed with `metagraph_string_free`.
+///
+/// Returns null on failure, with the reason written to `error` if it is not null.
+///
+/// # Safety
+///
+/// `preview` must be null or returned by `metagraph_scrape` and not yet freed,
+/// and `error` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn metagraph_preview_json(
+    preview: *const MetagraphPreview,
+    error: *mut MetagraphError,
+) -> *mut c_char {
+    let Some(preview) = preview.as_ref() else {
+        set_error(error, MetagraphError::NullArgument);
+        return ptr::null_mut();
+    };
+
+    let json = guard(Err(MetagraphError::Panic), || {
+        serde_json::to_string(&preview.preview)
+            .ok()
+            .and_then(|json| CString::new(json).ok())
+            .ok_or(MetagraphError::Serialize)
+    });
+    match json {
+        Ok(json) => {
+            set_error(error, MetagraphError::Ok);
+            json.into_raw()
+        }
+        Err(code) => {
+            set_error(error, code);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Free a preview returned by `metagraph_scrape`. Null is ignored.
+///
+/// # Safety
+///
+/// `preview` must be null or returned by `metagraph_scrape` and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn metagraph_preview_free(preview: *mut MetagraphPreview) {
+    if !preview.is_null() {
+        guard((), || drop(Box::from_raw(preview)));
+    }
+}
+
+/// Free a string returned by `metagraph_preview_json`. Null is ignored.
+///
+/// # Safety
+///
+/// `string` must be null or returned by `metagraph_preview_json` and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn metagraph_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        guard((), || drop(CString::from_raw(string)));
+    }
+}
+
+/// Describe an error code, as a static string, or "unknown error" for other values.
+///
+/// The code is taken as an `int`, since an out of range enum from C would be
+/// undefined behaviour.
+#[no_mangle]
+pub extern "C" fn metagraph_error_message(error: c_int) -> *const c_char {
+    let message = match MetagraphError::from_code(error) {
+        Some(MetagraphError::Ok) => c"ok",
+        Some(MetagraphError::NullArgument) => c"null argument",
+        Some(MetagraphError::InvalidUtf8) => c"invalid UTF-8",
+        Some(MetagraphError::InvalidUrl) => c"invalid url",
+        Some(MetagraphError::InvalidPropertyTag) => c"invalid property tag",
+        Some(MetagraphError::InvalidProperty) => c"invalid property",
+        Some(MetagraphError::InvalidContent) => c"invalid content",
+        Some(MetagraphError::Serialize) => c"serialization failed",
+        Some(MetagraphError::Panic) => c"internal error",
+        None => c"unknown error",
+    };
+    message.as_ptr()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn to_c_string_strips_nul() {
+        let text = to_c_string(&Some("a\0b".into())).unwrap();
+        assert_eq!(text.to_str().unwrap(), "ab");
+        assert!(to_c_string(&None).is_none());
+    }
+
+    #[test]
+    fn guard_catches_panic() {
+        let result = guard(Err(MetagraphError::Panic), || -> Result<(), _> {
+            panic!("scraper bug")
+        });
+        assert_eq!(result, Err(MetagraphError::Panic));
+    }
+}
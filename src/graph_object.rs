@@ -36,11 +36,15 @@ impl<TObject: GraphObject + Default> Update for Vec<TObject> {
 impl<TObject: GraphObject + Default> Update for Option<Vec<TObject>> {
     fn extend_or_update_last(&mut self, data: MetaData) -> Result<(), ParseError> {
         if self.is_none() && !TObject::should_create_new(data.tags) {
-            return Ok(());
-        } else {
-            let vector = self.get_or_insert(vec![]);
-            vector.extend_or_update_last(data)?;
             Ok(())
+        } else {
+            let mut vector = self.take().unwrap_or_default();
+            let result = vector.extend_or_update_last(data);
+            // Leave the list unset when its first object fails.
+            if !vector.is_empty() {
+                *self = Some(vector);
+            }
+            result
         }
     }
 }
@@ -0,0 +1,62 @@
+use scraper::Html;
+use serde::Serialize;
+use std::error::Error;
+use url::Url;
+
+use crate::{
+    open_graph::OpenGraphObject,
+    robots::{Directives, LinkGraph},
+    scraper::{OpenGraphScraper, Scraper},
+};
+
+/// Open Graph, robots directives and the link graph, from one parse of the head.
+#[derive(Default, Debug, Serialize)]
+pub struct Head {
+    pub open_graph: OpenGraphObject,
+    pub directives: Directives,
+    pub links: LinkGraph,
+}
+
+pub struct HeadScraper {}
+
+impl HeadScraper {
+    /// Scrape the document head.
+    pub fn scrape(url: &str, html: &str) -> Result<Head, Box<dyn Error>> {
+        // Parsing validates the supplied url.
+        let url = Url::parse(url)?;
+
+        let head = OpenGraphScraper::extract_head(html);
+        let document = Html::parse_document(head);
+
+        Ok(Head {
+            open_graph: OpenGraphScraper::scrape_document(&url, &document),
+            directives: Directives::from_document(&document),
+            links: LinkGraph::from_document(&url, &document),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scrape() {
+        let head = HeadScraper::scrape(
+            "http://x.com",
+            r#"<head>
+                <meta property="og:title" content="X" />
+                <meta name="robots" content="noindex" />
+                <link rel="canonical" href="/a" />
+            </head>
+            <body>
+                <link rel="canonical" href="/b" />
+            </body>"#,
+        )
+        .unwrap();
+
+        assert_eq!(head.open_graph.title, Some("X".into()));
+        assert!(head.directives.robots.noindex);
+        assert_eq!(head.links.canonical, Some("http://x.com/a".into()));
+    }
+}
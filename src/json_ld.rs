@@ -0,0 +1,108 @@
+use scraper::{Html, Selector};
+use serde_json::Value;
+use std::sync::LazyLock;
+
+/// Extracts schema.org nodes from `<script type="application/ld+json">` blocks.
+pub struct JsonLdScraper {}
+
+impl JsonLdScraper {
+    /// Get the CSS selector for JSON-LD script elements.
+    fn selector() -> &'static Selector {
+        static SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse(r#"script[type="application/ld+json"]"#).unwrap());
+        &SELECTOR
+    }
+
+    /// Scrape the document for JSON-LD nodes.
+    ///
+    /// Top level arrays and `@graph` containers are flattened, so each item is a
+    /// single node object. Blocks which are not valid JSON are skipped.
+    pub fn scrape(html: &str) -> Vec<Value> {
+        let document = Html::parse_document(html);
+        Self::scrape_document(&document)
+    }
+
+    pub(crate) fn scrape_document(document: &Html) -> Vec<Value> {
+        let mut nodes = vec![];
+
+        for element in document.select(Self::selector()) {
+            let text: String = element.text().collect();
+
+            if let Ok(value) = serde_json::from_str(&text) {
+                flatten_into(value, &mut nodes);
+            }
+        }
+        nodes
+    }
+}
+
+fn flatten_into(value: Value, nodes: &mut Vec<Value>) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                flatten_into(value, nodes);
+            }
+        }
+        Value::Object(mut object) => {
+            if let Some(graph) = object.remove("@graph") {
+                flatten_into(graph, nodes);
+            }
+            if object.contains_key("@type") {
+                nodes.push(Value::Object(object));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether a node has the given schema.org type, in any of its common spellings.
+pub fn has_type(node: &Value, schema_type: &str) -> bool {
+    let matches = |value: &Value| {
+        value.as_str().is_some_and(|value| {
+            value
+                .rsplit(['/', ':'])
+                .next()
+                .is_some_and(|name| name == schema_type)
+        })
+    };
+
+    match node.get("@type") {
+        Some(Value::Array(types)) => types.iter().any(matches),
+        Some(value) => matches(value),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn scrape_graph() {
+        let nodes = JsonLdScraper::scrape(
+            r#"<head>
+                <script type="application/ld+json">
+                    {"@context": "https://schema.org", "@graph": [
+                        {"@type": "WebPage"},
+                        {"@type": ["Recipe"], "name": "X"}
+                    ]}
+                </script>
+                <script type="application/ld+json">not json</script>
+            </head>"#,
+        );
+        assert_eq!(nodes.len(), 2);
+        assert!(has_type(&nodes[1], "Recipe"));
+    }
+
+    #[test]
+    fn type_spellings() {
+        assert!(has_type(
+            &json!({"@type": "http://schema.org/Recipe"}),
+            "Recipe"
+        ));
+        assert!(has_type(&json!({"@type": "schema:Recipe"}), "Recipe"));
+        assert!(!has_type(&json!({"@type": "NotARecipe"}), "Recipe"));
+    }
+}
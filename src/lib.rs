@@ -1,11 +1,71 @@
 mod scraper;
-pub use crate::scraper::{OpenGraphScraper, TwitterScraper};
+pub use crate::scraper::{
+    AppLinksScraper, FacebookScraper, OpenGraphScraper, ProductScraper, Scraper, TwitterScraper,
+};
 
 mod meta_data;
 
 mod graph_object;
 
-mod open_graph;
-mod twitter;
+pub mod open_graph;
+pub mod twitter;
+pub mod app_links;
+pub mod facebook;
+pub mod product;
+pub mod recipe;
+pub mod citation;
+pub mod fediverse;
+pub mod manifest;
+pub mod platform;
+pub mod robots;
+
+pub mod json_ld;
+pub mod microdata;
+pub mod microformats;
+pub mod rdfa;
+
+pub mod head;
+pub mod preview;
+pub mod validation;
+
+#[cfg(feature = "fetch")]
+pub mod fetch;
+
+#[cfg(feature = "batch")]
+pub mod batch;
+
+#[cfg(feature = "warc")]
+pub mod warc;
+
+#[cfg(feature = "capture")]
+pub mod capture;
+
+#[cfg(any(feature = "fetch", feature = "warc", feature = "capture"))]
+mod content_type;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "cdylib")]
+pub mod ffi;
+
+#[cfg(feature = "python")]
+mod python;
+
+#[cfg(all(target_arch = "wasm32", feature = "fetch"))]
+compile_error!("the `fetch` feature is not supported on wasm32");
 
 mod error;
+pub use error::{Diagnostic, ParseError};
+
+pub use citation::CitationScraper;
+pub use head::HeadScraper;
+pub use fediverse::FediverseScraper;
+pub use json_ld::JsonLdScraper;
+pub use manifest::ManifestScraper;
+pub use microdata::MicrodataScraper;
+pub use microformats::MicroformatsScraper;
+pub use platform::PlatformScraper;
+pub use preview::PreviewScraper;
+pub use rdfa::RdfaScraper;
+pub use recipe::RecipeScraper;
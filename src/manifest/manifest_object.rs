@@ -0,0 +1,176 @@
+use scraper::{Html, Selector};
+use serde::Serialize;
+use serde_json::Value;
+use std::{error::Error, sync::LazyLock};
+use url::Url;
+
+use super::property::Icon;
+use crate::{error::ParseError, meta_data::MetaData};
+
+/// A Web App Manifest, with urls resolved against the manifest url.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct WebAppManifest {
+    pub name: Option<String>,
+    pub short_name: Option<String>,
+    pub description: Option<String>,
+    pub start_url: Option<String>,
+    pub display: Option<String>,
+    pub theme_color: Option<String>,
+    pub background_color: Option<String>,
+    pub icons: Vec<Icon>,
+}
+
+impl WebAppManifest {
+    /// Parse the manifest JSON fetched from `manifest_url`.
+    ///
+    /// As in browsers, members of the wrong type are ignored rather than
+    /// failing the whole manifest.
+    pub fn parse(manifest_url: &str, json: &str) -> Result<Self, Box<dyn Error>> {
+        let manifest_url = Url::parse(manifest_url)?;
+
+        let value: Value = serde_json::from_str(json)?;
+        if !value.is_object() {
+            return Err(ParseError::InvalidContent(json.into()).into());
+        }
+
+        let string = |key| {
+            value
+                .get(key)
+                .and_then(Value::as_str)
+                .map(str::trim)
+                .filter(|string| !string.is_empty())
+                .map(String::from)
+        };
+
+        let icons = value
+            .get("icons")
+            .and_then(Value::as_array)
+            .map(|icons| {
+                icons
+                    .iter()
+                    .filter_map(|icon| Icon::from_json(icon, &manifest_url))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            name: string("name"),
+            short_name: string("short_name"),
+            description: string("description"),
+            start_url: string("start_url")
+                .and_then(|start_url| manifest_url.join(&start_url).ok())
+                .map(String::from),
+            display: string("display"),
+            theme_color: string("theme_color"),
+            background_color: string("background_color"),
+            icons,
+        })
+    }
+
+    /// The largest fixed size icon, falling back to the first icon.
+    pub fn largest_icon(&self) -> Option<&Icon> {
+        self.icons
+            .iter()
+            .filter(|icon| icon.max_width().is_some())
+            .max_by_key(|icon| icon.max_width())
+            .or_else(|| self.icons.first())
+    }
+}
+
+pub struct ManifestScraper {}
+
+impl ManifestScraper {
+    /// Get the CSS selector for the manifest link element.
+    fn selector() -> &'static Selector {
+        static SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse(r#"head > link[rel~="manifest"][href]"#).unwrap());
+        &SELECTOR
+    }
+
+    /// Find the manifest url linked from the document.
+    ///
+    /// The crate does not fetch, so callers request this url and pass the
+    /// response to `WebAppManifest::parse`.
+    pub fn discover(url: &str, html: &str) -> Result<Option<String>, Box<dyn Error>> {
+        // Parsing validates the supplied url.
+        let url = Url::parse(url)?;
+
+        let document = Html::parse_document(html);
+        let manifest_url = document
+            .select(Self::selector())
+            .next()
+            .and_then(|element| element.value().attr("href"))
+            .map(|href| {
+                MetaData {
+                    site_url: &url,
+                    tags: &[],
+                    content: href.trim(),
+                }
+                .normalized_url()
+            });
+
+        Ok(manifest_url)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::manifest::property::{IconPurpose, IconSize};
+
+    #[test]
+    fn discover() {
+        let manifest_url = ManifestScraper::discover(
+            "http://x.com/a/b",
+            r#"<head><link rel="manifest" href="/app.webmanifest" /></head>"#,
+        )
+        .unwrap();
+        assert_eq!(manifest_url, Some("http://x.com/app.webmanifest".into()));
+    }
+
+    #[test]
+    fn parse() {
+        let manifest = WebAppManifest::parse(
+            "http://cdn.x.com/static/manifest.json",
+            r##"{
+                "name": "X App",
+                "short_name": "X",
+                "start_url": "../?source=pwa",
+                "theme_color": "#000000",
+                "background_color": 1,
+                "icons": [
+                    {"src": "icon-192.png", "sizes": "192x192", "type": "image/png"},
+                    {"src": "icon.svg", "sizes": "any", "purpose": "maskable unknown"},
+                    {"sizes": "512x512"}
+                ]
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.name, Some("X App".into()));
+        assert_eq!(
+            manifest.start_url,
+            Some("http://cdn.x.com/?source=pwa".into())
+        );
+        assert_eq!(manifest.theme_color, Some("#000000".into()));
+        assert_eq!(manifest.background_color, None);
+        assert_eq!(manifest.icons.len(), 2);
+        assert_eq!(
+            manifest.icons[0].src,
+            "http://cdn.x.com/static/icon-192.png"
+        );
+        assert_eq!(manifest.icons[0].purpose, vec![IconPurpose::Any]);
+        assert_eq!(manifest.icons[1].sizes, vec![IconSize::Any]);
+        assert_eq!(manifest.icons[1].purpose, vec![IconPurpose::Maskable]);
+        assert_eq!(
+            manifest.largest_icon().map(|icon| icon.src.as_str()),
+            Some("http://cdn.x.com/static/icon-192.png")
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(WebAppManifest::parse("http://x.com/m.json", "[]").is_err());
+        assert!(WebAppManifest::parse("http://x.com/m.json", "{").is_err());
+    }
+}
@@ -0,0 +1,7 @@
+pub mod property;
+
+mod manifest_object;
+pub use manifest_object::{ManifestScraper, WebAppManifest};
+
+mod site_identity;
+pub use site_identity::SiteIdentity;
@@ -0,0 +1,114 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::str::FromStr;
+use strum::EnumString;
+use url::Url;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Icon {
+    /// The icon url, resolved against the manifest url.
+    pub src: String,
+    pub sizes: Vec<IconSize>,
+    pub media_type: Option<String>,
+    pub purpose: Vec<IconPurpose>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IconSize {
+    /// A scalable icon, e.g. SVG.
+    Any,
+    Pixels {
+        width: u32,
+        height: u32,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, Serialize)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+#[serde(rename_all = "snake_case")]
+pub enum IconPurpose {
+    Any,
+    Maskable,
+    Monochrome,
+}
+
+impl FromStr for IconSize {
+    type Err = ();
+
+    /// Parse a single size token, `any` or `WIDTHxHEIGHT`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("any") {
+            return Ok(IconSize::Any);
+        }
+        let size = s.to_ascii_lowercase();
+        let (width, height) = size.split_once('x').ok_or(())?;
+        Ok(IconSize::Pixels {
+            width: width.parse().map_err(|_| ())?,
+            height: height.parse().map_err(|_| ())?,
+        })
+    }
+}
+
+impl Icon {
+    /// Read an icon from a manifest `icons` entry, ignoring invalid members.
+    pub(crate) fn from_json(value: &Value, manifest_url: &Url) -> Option<Self> {
+        let src = manifest_url.join(value.get("src")?.as_str()?.trim()).ok()?;
+        let tokens = |key| {
+            value
+                .get(key)
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .split_whitespace()
+        };
+
+        let sizes = tokens("sizes")
+            .filter_map(|size| IconSize::from_str(size).ok())
+            .collect();
+
+        // Unknown purposes are ignored, and no valid purpose means `any`.
+        let mut purpose: Vec<IconPurpose> = tokens("purpose")
+            .filter_map(|purpose| IconPurpose::from_str(purpose).ok())
+            .collect();
+        if purpose.is_empty() {
+            purpose.push(IconPurpose::Any);
+        }
+
+        Some(Self {
+            src: src.into(),
+            sizes,
+            media_type: value.get("type").and_then(Value::as_str).map(String::from),
+            purpose,
+        })
+    }
+
+    /// The largest pixel width of the icon, if it has a fixed size.
+    pub fn max_width(&self) -> Option<u32> {
+        self.sizes
+            .iter()
+            .filter_map(|size| match size {
+                IconSize::Pixels { width, .. } => Some(*width),
+                IconSize::Any => None,
+            })
+            .max()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn size_from_str() {
+        assert_eq!(IconSize::from_str("any"), Ok(IconSize::Any));
+        assert_eq!(
+            IconSize::from_str("192X48"),
+            Ok(IconSize::Pixels {
+                width: 192,
+                height: 48
+            })
+        );
+        assert!(IconSize::from_str("192").is_err());
+        assert!(IconSize::from_str("x192").is_err());
+    }
+}
@@ -0,0 +1,2 @@
+mod icon;
+pub use icon::{Icon, IconPurpose, IconSize};
@@ -0,0 +1,103 @@
+use serde::Serialize;
+
+use super::{property::Icon, WebAppManifest};
+use crate::open_graph::OpenGraphObject;
+
+/// The identity of a site for site level cards, from Open Graph and the manifest.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct SiteIdentity {
+    pub name: Option<String>,
+    pub short_name: Option<String>,
+    pub theme_color: Option<String>,
+    pub background_color: Option<String>,
+    pub icons: Vec<Icon>,
+}
+
+impl SiteIdentity {
+    /// Merge the site identity, with `og:site_name` taking precedence for the name.
+    pub fn from_sources(open_graph: &OpenGraphObject, manifest: Option<&WebAppManifest>) -> Self {
+        let Some(manifest) = manifest else {
+            return Self {
+                name: open_graph.site_name.clone(),
+                ..Self::default()
+            };
+        };
+
+        Self {
+            name: open_graph
+                .site_name
+                .clone()
+                .or_else(|| manifest.name.clone())
+                .or_else(|| manifest.short_name.clone()),
+            short_name: manifest.short_name.clone(),
+            theme_color: manifest.theme_color.clone(),
+            background_color: manifest.background_color.clone(),
+            icons: manifest.icons.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn manifest() -> WebAppManifest {
+        WebAppManifest::parse(
+            "http://x.com/manifest.json",
+            r##"{
+                "name": "Manifest name",
+                "short_name": "Short",
+                "theme_color": "#000000",
+                "icons": [{ "src": "/icon.png", "sizes": "192x192" }]
+            }"##,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn from_sources_meta_precedence() {
+        let open_graph = OpenGraphObject {
+            site_name: Some("Open Graph name".into()),
+            ..OpenGraphObject::default()
+        };
+        let manifest = manifest();
+
+        let identity = SiteIdentity::from_sources(&open_graph, Some(&manifest));
+
+        assert_eq!(identity.name, Some("Open Graph name".into()));
+        assert_eq!(identity.short_name, Some("Short".into()));
+        assert_eq!(identity.theme_color, Some("#000000".into()));
+        assert_eq!(identity.icons[0].src, "http://x.com/icon.png");
+    }
+
+    #[test]
+    fn from_sources_manifest_fallback() {
+        let open_graph = OpenGraphObject::default();
+        let mut manifest = manifest();
+
+        let identity = SiteIdentity::from_sources(&open_graph, Some(&manifest));
+        assert_eq!(identity.name, Some("Manifest name".into()));
+
+        manifest.name = None;
+        let identity = SiteIdentity::from_sources(&open_graph, Some(&manifest));
+        assert_eq!(identity.name, Some("Short".into()));
+    }
+
+    #[test]
+    fn from_sources_without_manifest() {
+        let open_graph = OpenGraphObject {
+            site_name: Some("Open Graph name".into()),
+            ..OpenGraphObject::default()
+        };
+
+        let identity = SiteIdentity::from_sources(&open_graph, None);
+
+        assert_eq!(
+            identity,
+            SiteIdentity {
+                name: Some("Open Graph name".into()),
+                ..SiteIdentity::default()
+            }
+        );
+    }
+}
@@ -1,5 +1,22 @@
+use std::borrow::Cow;
 use url::Url;
 
+/// The most tags in a property, well above the deepest in use, e.g. `twitter:app:name:iphone`.
+pub const MAX_TAGS: usize = 8;
+
+/// Split a property into tags without allocating, or `None` if it has more than `MAX_TAGS`.
+pub fn split_tags<'a, 'b>(
+    property: &'a str,
+    buffer: &'b mut [&'a str; MAX_TAGS],
+) -> Option<&'b [&'a str]> {
+    let mut count = 0;
+    for tag in property.split(':') {
+        *buffer.get_mut(count)? = tag;
+        count += 1;
+    }
+    Some(&buffer[..count])
+}
+
 pub struct MetaData<'a> {
     pub site_url: &'a Url,
     pub tags: &'a [&'a str],
@@ -16,18 +33,33 @@ impl<'a> MetaData<'a> {
     }
 
     pub fn normalized_url(&self) -> String {
-        let url = Url::parse(self.content);
-
-        if let Err(parse_error) = url {
-            if parse_error == url::ParseError::RelativeUrlWithoutBase {
-                let full_url = self.site_url.join(self.content);
+        normalize_url(self.site_url, self.content).into_owned()
+    }
+}
 
-                if let Ok(full_url) = full_url {
-                    return full_url.into();
-                }
-            }
+/// Resolve a relative url against the site url, borrowing the content if unchanged.
+pub fn normalize_url<'a>(site_url: &Url, content: &'a str) -> Cow<'a, str> {
+    if let Err(url::ParseError::RelativeUrlWithoutBase) = Url::parse(content) {
+        if let Ok(full_url) = site_url.join(content) {
+            return Cow::Owned(full_url.into());
         }
+    }
+
+    Cow::Borrowed(content)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn split() {
+        let mut buffer = [""; MAX_TAGS];
 
-        return self.content.into();
+        assert_eq!(
+            split_tags("og:image:width", &mut buffer),
+            Some(&["og", "image", "width"][..])
+        );
+        assert_eq!(split_tags("a:b:c:d:e:f:g:h:i", &mut buffer), None);
     }
 }
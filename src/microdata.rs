@@ -0,0 +1,260 @@
+use scraper::{ElementRef, Html, Selector};
+use serde_json::{Map, Value};
+use std::{
+    collections::{HashMap, HashSet},
+    error::Error,
+    sync::LazyLock,
+};
+use url::Url;
+
+use crate::meta_data::MetaData;
+
+/// Extracts HTML microdata items, in the same node shape as `JsonLdScraper`.
+///
+/// Each item becomes an object with `@type` (from `itemtype`), `@id` (from
+/// `itemid`) and its properties. Repeated properties become arrays and nested
+/// items become nested objects.
+pub struct MicrodataScraper {}
+
+/// Shared state while walking one document.
+struct Document<'a> {
+    site_url: &'a Url,
+    ids: HashMap<&'a str, ElementRef<'a>>,
+}
+
+impl MicrodataScraper {
+    /// Get the CSS selector for top level items.
+    fn selector() -> &'static Selector {
+        static SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("[itemscope]:not([itemprop])").unwrap());
+        &SELECTOR
+    }
+
+    /// Scrape the whole document, not only the head, for microdata items.
+    pub fn scrape(url: &str, html: &str) -> Result<Vec<Value>, Box<dyn Error>> {
+        // Parsing validates the supplied url.
+        let url = Url::parse(url)?;
+
+        let document = Html::parse_document(html);
+        static ID_SELECTOR: LazyLock<Selector> = LazyLock::new(|| Selector::parse("[id]").unwrap());
+
+        let mut ids = HashMap::new();
+        for element in document.select(&ID_SELECTOR) {
+            if let Some(id) = element.value().id() {
+                // The first element with a given id wins.
+                ids.entry(id).or_insert(element);
+            }
+        }
+
+        let walker = Document {
+            site_url: &url,
+            ids,
+        };
+
+        let items = document
+            .select(Self::selector())
+            .map(|element| walker.item(element, &mut vec![]))
+            .collect();
+
+        Ok(items)
+    }
+}
+
+impl<'a> Document<'a> {
+    /// Build the node for an item, skipping any item already being built above it.
+    fn item(&self, root: ElementRef<'a>, ancestors: &mut Vec<ElementRef<'a>>) -> Value {
+        let mut node = Map::new();
+
+        if let Some(types) = root.value().attr("itemtype") {
+            let types: Vec<Value> = types.split_whitespace().map(Value::from).collect();
+            match types.len() {
+                0 => {}
+                1 => {
+                    node.insert("@type".into(), types.into_iter().next().unwrap());
+                }
+                _ => {
+                    node.insert("@type".into(), Value::Array(types));
+                }
+            }
+        }
+        if let Some(id) = root.value().attr("itemid") {
+            node.insert("@id".into(), self.url(id).into());
+        }
+
+        ancestors.push(root);
+
+        for property in self.properties(root) {
+            let value = if property.value().attr("itemscope").is_some() {
+                if ancestors.contains(&property) {
+                    continue;
+                }
+                self.item(property, ancestors)
+            } else {
+                self.value(property).into()
+            };
+
+            let names = property.value().attr("itemprop").unwrap_or_default();
+            for name in names.split_whitespace() {
+                insert(&mut node, name, value.clone());
+            }
+        }
+
+        ancestors.pop();
+
+        Value::Object(node)
+    }
+
+    /// Collect the property elements of an item, in document order.
+    fn properties(&self, root: ElementRef<'a>) -> Vec<ElementRef<'a>> {
+        let mut pending: Vec<ElementRef> = root.children().filter_map(ElementRef::wrap).collect();
+
+        if let Some(refs) = root.value().attr("itemref") {
+            pending.extend(refs.split_whitespace().filter_map(|id| self.ids.get(id)));
+        }
+
+        let mut visited = HashSet::from([root.id()]);
+        let mut properties = vec![];
+
+        // Depth first, so reversed to pop in document order.
+        pending.reverse();
+
+        while let Some(element) = pending.pop() {
+            if !visited.insert(element.id()) {
+                continue;
+            }
+
+            if element.value().attr("itemprop").is_some() {
+                properties.push(element);
+            }
+
+            // Nested items own their descendants.
+            if element.value().attr("itemscope").is_none() {
+                let children: Vec<ElementRef> =
+                    element.children().filter_map(ElementRef::wrap).collect();
+                pending.extend(children.into_iter().rev());
+            }
+        }
+
+        properties
+    }
+
+    /// Get the value of a non-item property, according to its element type.
+    fn value(&self, element: ElementRef) -> String {
+        let element_value = element.value();
+        let attr = |name| element_value.attr(name).unwrap_or_default();
+
+        match element_value.name() {
+            "meta" => attr("content").into(),
+            "audio" | "embed" | "iframe" | "img" | "source" | "track" | "video" => {
+                self.url(attr("src"))
+            }
+            "a" | "area" | "link" => self.url(attr("href")),
+            "object" => self.url(attr("data")),
+            "data" | "meter" => attr("value").into(),
+            "time" if element_value.attr("datetime").is_some() => attr("datetime").into(),
+            _ => element.text().collect::<String>().trim().into(),
+        }
+    }
+
+    fn url(&self, content: &str) -> String {
+        let meta_data = MetaData {
+            site_url: self.site_url,
+            tags: &[],
+            content: content.trim(),
+        };
+        meta_data.normalized_url()
+    }
+}
+
+/// Insert a property value, turning repeated properties into an array.
+fn insert(node: &mut Map<String, Value>, name: &str, value: Value) {
+    match node.get_mut(name) {
+        None => {
+            node.insert(name.into(), value);
+        }
+        Some(Value::Array(values)) => values.push(value),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, value]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+    use crate::json_ld::has_type;
+
+    // Helper to scrape HTML string.
+    fn scrape(html: &str) -> Vec<Value> {
+        MicrodataScraper::scrape("http://x.com", html).unwrap()
+    }
+
+    #[test]
+    fn scrape_nested() {
+        let items = scrape(
+            r#"<body>
+                <div itemscope itemtype="https://schema.org/Product">
+                    <span itemprop="name"> X </span>
+                    <img itemprop="image" src="./x.jpg" />
+                    <div itemprop="offers" itemscope itemtype="https://schema.org/Offer">
+                        <meta itemprop="priceCurrency" content="USD" />
+                        <data itemprop="price" value="10">$10</data>
+                    </div>
+                    <time itemprop="releaseDate" datetime="2020-01-01">January</time>
+                </div>
+            </body>"#,
+        );
+        assert_eq!(items.len(), 1);
+        assert!(has_type(&items[0], "Product"));
+        assert_eq!(
+            items[0],
+            json!({
+                "@type": "https://schema.org/Product",
+                "name": "X",
+                "image": "http://x.com/x.jpg",
+                "offers": {
+                    "@type": "https://schema.org/Offer",
+                    "priceCurrency": "USD",
+                    "price": "10",
+                },
+                "releaseDate": "2020-01-01",
+            })
+        );
+    }
+
+    #[test]
+    fn scrape_repeated_and_itemref() {
+        let items = scrape(
+            r#"<body>
+                <div itemscope itemref="extra">
+                    <a itemprop="sameAs url" href="/a">A</a>
+                    <a itemprop="sameAs" href="/b">B</a>
+                </div>
+                <p id="extra" itemprop="name">X</p>
+            </body>"#,
+        );
+        assert_eq!(
+            items[0],
+            json!({
+                "sameAs": ["http://x.com/a", "http://x.com/b"],
+                "url": "http://x.com/a",
+                "name": "X",
+            })
+        );
+    }
+
+    #[test]
+    fn scrape_itemref_cycle() {
+        let items = scrape(
+            r#"<body>
+                <div id="a" itemscope itemref="b">
+                    <div id="b" itemprop="child" itemscope itemref="a"></div>
+                </div>
+            </body>"#,
+        );
+        assert_eq!(items.len(), 1);
+    }
+}
@@ -0,0 +1,87 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// A parsed microformats2 document, serializing to the standard mf2 JSON.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct Mf2Document {
+    pub items: Vec<Mf2Item>,
+    pub rels: BTreeMap<String, Vec<String>>,
+    #[serde(rename = "rel-urls")]
+    pub rel_urls: BTreeMap<String, RelUrl>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct Mf2Item {
+    #[serde(rename = "type")]
+    pub types: Vec<String>,
+    pub properties: BTreeMap<String, Vec<PropertyValue>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Mf2Item>,
+    /// The plain value of an item which is itself a property, e.g. an author's name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum PropertyValue {
+    Text(String),
+    Image { value: String, alt: String },
+    Html { html: String, value: String },
+    Item(Box<Mf2Item>),
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct RelUrl {
+    pub rels: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hreflang: Option<String>,
+}
+
+impl Mf2Item {
+    pub fn has_type(&self, item_type: &str) -> bool {
+        self.types.iter().any(|types| types == item_type)
+    }
+
+    /// Get the first value of a property.
+    pub fn first(&self, name: &str) -> Option<&PropertyValue> {
+        self.properties.get(name)?.first()
+    }
+
+    /// Get the first value of a property as text.
+    pub fn first_text(&self, name: &str) -> Option<&str> {
+        self.first(name).and_then(PropertyValue::as_text)
+    }
+
+    /// Find the first item of a type, searching children depth first.
+    pub fn find(&self, item_type: &str) -> Option<&Mf2Item> {
+        if self.has_type(item_type) {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(item_type))
+    }
+}
+
+impl PropertyValue {
+    /// The plain text of a value, e.g. the url of an image or the value of an item.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            PropertyValue::Text(text) => Some(text),
+            PropertyValue::Image { value, .. } | PropertyValue::Html { value, .. } => Some(value),
+            PropertyValue::Item(item) => item.value.as_deref(),
+        }
+    }
+}
+
+impl Mf2Document {
+    /// Find the first item of a type, searching items and their children.
+    pub fn find(&self, item_type: &str) -> Option<&Mf2Item> {
+        self.items.iter().find_map(|item| item.find(item_type))
+    }
+}
@@ -0,0 +1,8 @@
+mod document;
+pub use document::{Mf2Document, Mf2Item, PropertyValue, RelUrl};
+
+mod parser;
+pub use parser::MicroformatsScraper;
+
+mod preview;
+pub use preview::EntryPreview;
@@ -0,0 +1,429 @@
+use scraper::{ElementRef, Html, Selector};
+use std::{error::Error, sync::LazyLock};
+use url::Url;
+
+use super::{Mf2Document, Mf2Item, PropertyValue};
+use crate::meta_data::MetaData;
+
+/// Parses microformats2 items and rel links from a document.
+pub struct MicroformatsScraper {}
+
+/// The property class prefixes, which determine how a value is parsed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Prefix {
+    Plain,
+    Url,
+    DateTime,
+    Embedded,
+}
+
+/// Whether a class name suffix is a valid microformats name, e.g. `entry` or `x-entry`.
+fn is_name(name: &str) -> bool {
+    let mut segments = name.split('-');
+    let is_letters = |segment: &str| {
+        !segment.is_empty() && segment.bytes().all(|byte| byte.is_ascii_lowercase())
+    };
+
+    let Some(first) = segments.next() else {
+        return false;
+    };
+    let rest: Vec<&str> = segments.collect();
+
+    // Only a vendor prefix, which must be followed by a name, may contain digits.
+    let first_valid = is_letters(first)
+        || (!rest.is_empty()
+            && !first.is_empty()
+            && first
+                .bytes()
+                .all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit()));
+
+    first_valid && rest.into_iter().all(is_letters)
+}
+
+fn root_classes(element: &ElementRef) -> Vec<String> {
+    let mut classes: Vec<String> = element
+        .value()
+        .classes()
+        .filter(|class| class.strip_prefix("h-").is_some_and(is_name))
+        .map(String::from)
+        .collect();
+    classes.sort();
+    classes.dedup();
+    classes
+}
+
+fn property_classes(element: &ElementRef) -> Vec<(Prefix, String)> {
+    let mut properties = vec![];
+
+    for class in element.value().classes() {
+        let Some((prefix, name)) = class.split_once('-') else {
+            continue;
+        };
+        let prefix = match prefix {
+            "p" => Prefix::Plain,
+            "u" => Prefix::Url,
+            "dt" => Prefix::DateTime,
+            "e" => Prefix::Embedded,
+            _ => continue,
+        };
+        if is_name(name) && !properties.iter().any(|(_, existing)| existing == name) {
+            properties.push((prefix, name.into()));
+        }
+    }
+    properties
+}
+
+fn child_elements<'a>(element: &ElementRef<'a>) -> impl Iterator<Item = ElementRef<'a>> {
+    element.children().filter_map(ElementRef::wrap)
+}
+
+/// Get the only child element, if it is of the given name and not itself an item.
+fn only_child<'a>(element: &ElementRef<'a>, names: &[&str]) -> Option<ElementRef<'a>> {
+    let mut children = child_elements(element);
+    let child = children.next()?;
+
+    if children.next().is_some()
+        || !names.contains(&child.value().name())
+        || !root_classes(&child).is_empty()
+    {
+        return None;
+    }
+    Some(child)
+}
+
+fn text(element: &ElementRef) -> String {
+    element.text().collect::<String>().trim().into()
+}
+
+/// Properties found while walking an item, used to decide implied properties.
+#[derive(Default)]
+struct Found {
+    plain: bool,
+    url: bool,
+    nested: bool,
+}
+
+struct Parser<'a> {
+    site_url: &'a Url,
+}
+
+impl<'a> Parser<'a> {
+    fn url(&self, content: &str) -> String {
+        let meta_data = MetaData {
+            site_url: self.site_url,
+            tags: &[],
+            content: content.trim(),
+        };
+        meta_data.normalized_url()
+    }
+
+    fn attr<'b>(element: &ElementRef<'b>, names: &[&str], name: &str) -> Option<&'b str> {
+        if names.contains(&element.value().name()) {
+            element.value().attr(name)
+        } else {
+            None
+        }
+    }
+
+    /// Find the top level items, which are not nested in another item.
+    fn items(&self, element: &ElementRef, items: &mut Vec<Mf2Item>) {
+        for child in child_elements(element) {
+            if root_classes(&child).is_empty() {
+                self.items(&child, items);
+            } else {
+                items.push(self.item(&child));
+            }
+        }
+    }
+
+    fn item(&self, element: &ElementRef) -> Mf2Item {
+        let mut item = Mf2Item {
+            types: root_classes(element),
+            ..Mf2Item::default()
+        };
+        let mut found = Found::default();
+
+        self.walk(element, &mut item, &mut found);
+        self.imply(element, &mut item, &found);
+
+        item
+    }
+
+    fn walk(&self, element: &ElementRef, item: &mut Mf2Item, found: &mut Found) {
+        for child in child_elements(element) {
+            let properties = property_classes(&child);
+
+            if !root_classes(&child).is_empty() {
+                found.nested = true;
+                let mut nested = self.item(&child);
+
+                if properties.is_empty() {
+                    item.children.push(nested);
+                    continue;
+                }
+
+                for (prefix, name) in properties {
+                    nested.value = match prefix {
+                        Prefix::Plain => nested.first_text("name").map(String::from),
+                        Prefix::Url => nested.first_text("url").map(String::from),
+                        _ => None,
+                    }
+                    .or_else(|| self.value(prefix, &child).as_text().map(String::from));
+
+                    found.plain |= matches!(prefix, Prefix::Plain | Prefix::Embedded);
+                    found.url |= prefix == Prefix::Url;
+                    let value = PropertyValue::Item(Box::new(nested.clone()));
+                    item.properties.entry(name).or_default().push(value);
+                }
+                continue;
+            }
+
+            for (prefix, name) in properties {
+                found.plain |= matches!(prefix, Prefix::Plain | Prefix::Embedded);
+                found.url |= prefix == Prefix::Url;
+                let value = self.value(prefix, &child);
+                item.properties.entry(name).or_default().push(value);
+            }
+            self.walk(&child, item, found);
+        }
+    }
+
+    fn value(&self, prefix: Prefix, element: &ElementRef) -> PropertyValue {
+        let value = |names: &[&str], name| Self::attr(element, names, name).map(String::from);
+
+        match prefix {
+            Prefix::Plain => {
+                let content = value(&["abbr", "link"], "title")
+                    .or_else(|| value(&["data", "input"], "value"))
+                    .or_else(|| value(&["img", "area"], "alt"))
+                    .unwrap_or_else(|| text(element));
+                PropertyValue::Text(content)
+            }
+            Prefix::Url => {
+                let url = value(&["a", "area", "link"], "href")
+                    .or_else(|| value(&["img", "audio", "video", "source", "iframe"], "src"))
+                    .or_else(|| value(&["video"], "poster"))
+                    .or_else(|| value(&["object"], "data"))
+                    .map(|url| self.url(&url));
+
+                if let (Some(url), Some(alt)) = (&url, value(&["img"], "alt")) {
+                    return PropertyValue::Image {
+                        value: url.clone(),
+                        alt,
+                    };
+                }
+
+                let content = url
+                    .or_else(|| value(&["abbr"], "title"))
+                    .or_else(|| value(&["data", "input"], "value"))
+                    .unwrap_or_else(|| text(element));
+                PropertyValue::Text(content)
+            }
+            Prefix::DateTime => {
+                let content = value(&["time", "ins", "del"], "datetime")
+                    .or_else(|| value(&["abbr"], "title"))
+                    .or_else(|| value(&["data", "input"], "value"))
+                    .unwrap_or_else(|| text(element));
+                PropertyValue::Text(content)
+            }
+            Prefix::Embedded => PropertyValue::Html {
+                html: element.inner_html().trim().into(),
+                value: text(element),
+            },
+        }
+    }
+
+    /// Add the implied `name`, `photo` and `url` properties.
+    fn imply(&self, element: &ElementRef, item: &mut Mf2Item, found: &Found) {
+        let properties = &mut item.properties;
+
+        if !properties.contains_key("name") && !found.plain && !found.nested {
+            let child = only_child(element, &["img", "area", "abbr"]);
+            let name = [Some(*element), child]
+                .into_iter()
+                .flatten()
+                .find_map(|element| {
+                    Self::attr(&element, &["img", "area"], "alt")
+                        .or_else(|| Self::attr(&element, &["abbr"], "title"))
+                        .filter(|name| !name.is_empty())
+                })
+                .map(String::from)
+                .unwrap_or_else(|| text(element));
+            properties.insert("name".into(), vec![PropertyValue::Text(name)]);
+        }
+
+        if found.url || found.nested {
+            return;
+        }
+
+        if !properties.contains_key("photo") {
+            let photo = Self::attr(element, &["img"], "src")
+                .map(|src| (*element, src))
+                .or_else(|| {
+                    let child = only_child(element, &["img"])?;
+                    Some((child, child.value().attr("src")?))
+                });
+
+            if let Some((image, src)) = photo {
+                let value = self.url(src);
+                let photo = match image.value().attr("alt") {
+                    Some(alt) => PropertyValue::Image {
+                        value,
+                        alt: alt.into(),
+                    },
+                    None => PropertyValue::Text(value),
+                };
+                properties.insert("photo".into(), vec![photo]);
+            }
+        }
+
+        if !properties.contains_key("url") {
+            let href = Self::attr(element, &["a", "area"], "href").or_else(|| {
+                only_child(element, &["a", "area"]).and_then(|child| child.value().attr("href"))
+            });
+
+            if let Some(href) = href {
+                properties.insert("url".into(), vec![PropertyValue::Text(self.url(href))]);
+            }
+        }
+    }
+
+    fn rels(&self, document: &Html, result: &mut Mf2Document) {
+        static SELECTOR: LazyLock<Selector> = LazyLock::new(|| {
+            Selector::parse("a[rel][href], area[rel][href], link[rel][href]").unwrap()
+        });
+
+        for element in document.select(&SELECTOR) {
+            let value = element.value();
+            let url = self.url(value.attr("href").unwrap_or_default());
+            let rel_url = result.rel_urls.entry(url.clone()).or_default();
+
+            for rel in value.attr("rel").unwrap_or_default().split_whitespace() {
+                let urls = result.rels.entry(rel.into()).or_default();
+                if !urls.contains(&url) {
+                    urls.push(url.clone());
+                }
+                if !rel_url.rels.iter().any(|existing| existing == rel) {
+                    rel_url.rels.push(rel.into());
+                }
+            }
+
+            if value.name() == "a" && rel_url.text.is_none() {
+                rel_url.text = Some(text(&element));
+            }
+            rel_url.title = rel_url
+                .title
+                .take()
+                .or(value.attr("title").map(String::from));
+            rel_url.media_type = rel_url
+                .media_type
+                .take()
+                .or(value.attr("type").map(String::from));
+            rel_url.hreflang = rel_url
+                .hreflang
+                .take()
+                .or(value.attr("hreflang").map(String::from));
+        }
+    }
+}
+
+impl MicroformatsScraper {
+    /// Parse an already parsed document for microformats.
+    pub fn parse(url: &Url, document: &Html) -> Mf2Document {
+        let parser = Parser { site_url: url };
+        let mut result = Mf2Document::default();
+
+        parser.items(&document.root_element(), &mut result.items);
+        parser.rels(document, &mut result);
+
+        result
+    }
+
+    /// Scrape the whole document for microformats.
+    pub fn scrape(url: &str, html: &str) -> Result<Mf2Document, Box<dyn Error>> {
+        // Parsing validates the supplied url.
+        let url = Url::parse(url)?;
+
+        let document = Html::parse_document(html);
+        Ok(Self::parse(&url, &document))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    // Helper to scrape HTML string.
+    fn scrape(html: &str) -> Mf2Document {
+        MicroformatsScraper::scrape("http://x.com", html).unwrap()
+    }
+
+    #[test]
+    fn names() {
+        assert!(is_name("entry"));
+        assert!(is_name("x-entry"));
+        assert!(is_name("x2-entry"));
+        assert!(!is_name("x2"));
+        assert!(!is_name("Entry"));
+        assert!(!is_name("entry-"));
+    }
+
+    #[test]
+    fn scrape_entry() {
+        let result = scrape(
+            r#"<body>
+                <article class="h-entry">
+                    <h1 class="p-name">Title</h1>
+                    <a class="p-author h-card" href="/me">
+                        <img class="u-photo" src="/me.jpg" alt="" />Author
+                    </a>
+                    <time class="dt-published" datetime="2020-01-01T10:00:00Z">1 Jan</time>
+                    <div class="e-content"><p>Hi</p></div>
+                    <a href="/tag" rel="tag">Tag</a>
+                </article>
+            </body>"#,
+        );
+
+        assert_eq!(
+            serde_json::to_value(&result).unwrap(),
+            json!({
+                "items": [{
+                    "type": ["h-entry"],
+                    "properties": {
+                        "name": ["Title"],
+                        "author": [{
+                            "type": ["h-card"],
+                            "properties": {
+                                "name": ["Author"],
+                                "photo": [{"value": "http://x.com/me.jpg", "alt": ""}],
+                            },
+                            "value": "Author",
+                        }],
+                        "published": ["2020-01-01T10:00:00Z"],
+                        "content": [{"html": "<p>Hi</p>", "value": "Hi"}],
+                    },
+                }],
+                "rels": {"tag": ["http://x.com/tag"]},
+                "rel-urls": {"http://x.com/tag": {"rels": ["tag"], "text": "Tag"}},
+            })
+        );
+    }
+
+    #[test]
+    fn scrape_implied() {
+        let result = scrape(
+            r#"<body>
+                <a class="h-card" href="/me"><img src="/me.jpg" alt="Me" /></a>
+                <div><span class="h-card">Nested</span></div>
+            </body>"#,
+        );
+
+        assert_eq!(result.items.len(), 2);
+        let card = &result.items[0];
+        assert_eq!(card.first_text("name"), Some("Me"));
+        assert_eq!(card.first_text("url"), Some("http://x.com/me"));
+        assert_eq!(result.items[1].first_text("name"), Some("Nested"));
+    }
+}
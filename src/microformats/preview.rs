@@ -0,0 +1,126 @@
+use scraper::Html;
+use serde::Serialize;
+use std::error::Error;
+use url::Url;
+
+use super::{Mf2Document, Mf2Item, MicroformatsScraper, PropertyValue};
+use crate::{
+    open_graph::OpenGraphObject,
+    scraper::{OpenGraphScraper, Scraper},
+};
+
+/// A link preview for a blog post, from Open Graph with microformats as a fallback.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct EntryPreview {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub image: Option<String>,
+    pub author_name: Option<String>,
+    pub author_photo: Option<String>,
+    pub published: Option<String>,
+}
+
+fn first_text(item: &Mf2Item, name: &str) -> Option<String> {
+    item.first_text(name).map(String::from)
+}
+
+impl EntryPreview {
+    /// Build a preview, preferring Open Graph and filling gaps from the first `h-entry`.
+    ///
+    /// Open Graph has no author or publish date, so those always come from
+    /// microformats: the entry's `author`, or the page's representative `h-card`.
+    pub fn from_sources(open_graph: OpenGraphObject, microformats: &Mf2Document) -> Self {
+        let mut preview = Self {
+            title: open_graph.title,
+            description: open_graph.description,
+            url: open_graph.url,
+            image: open_graph
+                .images
+                .and_then(|images| images.into_iter().next())
+                .map(|image| image.url),
+            ..Self::default()
+        };
+
+        let Some(entry) = microformats.find("h-entry") else {
+            return preview;
+        };
+
+        preview.title = preview.title.or_else(|| first_text(entry, "name"));
+        preview.description = preview.description.or_else(|| first_text(entry, "summary"));
+        preview.url = preview.url.or_else(|| first_text(entry, "url"));
+        preview.image = preview.image.or_else(|| first_text(entry, "photo"));
+        preview.published = first_text(entry, "published");
+
+        match entry.first("author") {
+            Some(PropertyValue::Item(author)) => {
+                preview.author_name = first_text(author, "name");
+                preview.author_photo = first_text(author, "photo");
+            }
+            Some(author) => {
+                preview.author_name = author.as_text().map(String::from);
+            }
+            None => {
+                if let Some(card) = microformats.find("h-card") {
+                    preview.author_name = first_text(card, "name");
+                    preview.author_photo = first_text(card, "photo");
+                }
+            }
+        }
+
+        preview
+    }
+
+    /// Scrape the document for a blog post preview.
+    pub fn scrape(url: &str, html: &str) -> Result<Self, Box<dyn Error>> {
+        let open_graph = OpenGraphScraper::scrape(url, html)?;
+        let microformats =
+            MicroformatsScraper::parse(&Url::parse(url)?, &Html::parse_document(html));
+
+        Ok(Self::from_sources(open_graph, &microformats))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ENTRY: &str = r#"
+        <article class="h-entry">
+            <h1 class="p-name">Entry title</h1>
+            <span class="p-author h-card">
+                <img class="u-photo" src="/me.jpg" /><span class="p-name">Author</span>
+            </span>
+            <time class="dt-published" datetime="2020-01-01">1 Jan</time>
+        </article>
+    "#;
+
+    #[test]
+    fn scrape_without_open_graph() {
+        let preview =
+            EntryPreview::scrape("http://x.com", &format!("<body>{}</body>", ENTRY)).unwrap();
+
+        assert_eq!(
+            preview,
+            EntryPreview {
+                title: Some("Entry title".into()),
+                author_name: Some("Author".into()),
+                author_photo: Some("http://x.com/me.jpg".into()),
+                published: Some("2020-01-01".into()),
+                ..EntryPreview::default()
+            }
+        );
+    }
+
+    #[test]
+    fn scrape_prefers_open_graph() {
+        let html = format!(
+            r#"<head><meta property="og:title" content="X" /></head><body>{}</body>"#,
+            ENTRY
+        );
+        let preview = EntryPreview::scrape("http://x.com", &html).unwrap();
+
+        assert_eq!(preview.title, Some("X".into()));
+        assert_eq!(preview.author_name, Some("Author".into()));
+    }
+}
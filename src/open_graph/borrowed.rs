@@ -0,0 +1,189 @@
+use scraper::Html;
+use std::borrow::Cow;
+use url::Url;
+
+use super::{property::Image, OpenGraphObject};
+use crate::{
+    error::ParseError,
+    graph_object::GraphObject,
+    meta_data::{normalize_url, split_tags, MetaData, MAX_TAGS},
+    scraper::{OpenGraphScraper, Scraper},
+};
+
+fn parse_dimension(content: &str) -> Result<u32, ParseError> {
+    content
+        .parse()
+        .map_err(|_| ParseError::InvalidContent(content.into()))
+}
+
+/// An Open Graph image borrowing its text from the parsed document.
+#[derive(Default, Debug, PartialEq)]
+pub struct BorrowedImage<'a> {
+    pub url: Cow<'a, str>,
+    pub secure_url: Option<Cow<'a, str>>,
+    pub media_type: Option<Cow<'a, str>>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub alt: Option<Cow<'a, str>>,
+}
+
+impl<'a> BorrowedImage<'a> {
+    fn update_from(
+        &mut self,
+        site_url: &Url,
+        tags: &[&str],
+        content: &'a str,
+    ) -> Result<(), ParseError> {
+        match tags {
+            [] | ["url"] => self.url = normalize_url(site_url, content),
+            ["secure_url"] => self.secure_url = Some(content.into()),
+            ["type"] => self.media_type = Some(content.into()),
+            ["width"] => self.width = Some(parse_dimension(content)?),
+            ["height"] => self.height = Some(parse_dimension(content)?),
+            ["alt"] => self.alt = Some(content.into()),
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+
+    pub fn into_owned(self) -> Image {
+        Image {
+            url: self.url.into_owned(),
+            secure_url: self.secure_url.map(Cow::into_owned),
+            media_type: self.media_type.map(Cow::into_owned),
+            width: self.width,
+            height: self.height,
+            alt: self.alt.map(Cow::into_owned),
+        }
+    }
+}
+
+/// Open Graph borrowing its text from the parsed document, for hot paths reading a few fields.
+///
+/// Text is only copied where scraping changes it, i.e. relative urls resolved against
+/// the site url. Entities are decoded by the HTML parser, so borrowed text is decoded.
+#[derive(Default, Debug)]
+pub struct BorrowedOpenGraphObject<'a> {
+    pub title: Option<Cow<'a, str>>,
+    pub media_type: Option<Cow<'a, str>>,
+    pub url: Option<Cow<'a, str>>,
+    pub description: Option<Cow<'a, str>>,
+    pub site_name: Option<Cow<'a, str>>,
+    pub images: Option<Vec<BorrowedImage<'a>>>,
+    /// The remaining properties, such as audio and video, which are always owned.
+    pub other: OpenGraphObject,
+}
+
+impl<'a> BorrowedOpenGraphObject<'a> {
+    /// Scrape an already parsed document, borrowing from it.
+    pub fn scrape_document(url: &Url, document: &'a Html) -> Self {
+        let mut result = Self::default();
+
+        for element in document.select(OpenGraphScraper::selector()) {
+            let property = OpenGraphScraper::get_property(&element);
+            let content = OpenGraphScraper::get_content(&element);
+
+            if let (Some(property), Some(content)) = (property, content) {
+                let mut buffer = [""; MAX_TAGS];
+                let Some(property_tags) = split_tags(property, &mut buffer) else {
+                    continue;
+                };
+
+                let _ = result.update_from(url, &property_tags[1..], content);
+            }
+        }
+
+        result
+    }
+
+    fn update_from(
+        &mut self,
+        site_url: &Url,
+        tags: &[&str],
+        content: &'a str,
+    ) -> Result<(), ParseError> {
+        match tags {
+            ["title"] => self.title = Some(content.into()),
+            ["type"] => self.media_type = Some(content.into()),
+            ["url"] => self.url = Some(content.into()),
+            ["description"] => self.description = Some(content.into()),
+            ["site_name"] => self.site_name = Some(content.into()),
+            [first_tag, tags @ ..] if *first_tag == Image::prefix() => {
+                if Image::should_create_new(tags) {
+                    let mut image = BorrowedImage::default();
+                    image.update_from(site_url, tags, content)?;
+                    self.images.get_or_insert_with(Vec::new).push(image);
+                } else if let Some(image) =
+                    self.images.as_mut().and_then(|images| images.last_mut())
+                {
+                    image.update_from(site_url, tags, content)?;
+                }
+            }
+            _ => {
+                self.other.update_from(MetaData {
+                    site_url,
+                    tags,
+                    content,
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn into_owned(self) -> OpenGraphObject {
+        OpenGraphObject {
+            title: self.title.map(Cow::into_owned),
+            media_type: self.media_type.map(Cow::into_owned),
+            url: self.url.map(Cow::into_owned),
+            description: self.description.map(Cow::into_owned),
+            site_name: self.site_name.map(Cow::into_owned),
+            images: self
+                .images
+                .map(|images| images.into_iter().map(BorrowedImage::into_owned).collect()),
+            ..self.other
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const HTML: &str = r#"<head>
+        <meta property="og:title" content="Fish &amp; Chips" />
+        <meta property="og:image" content="/a.jpg" />
+        <meta property="og:image:width" content="300" />
+        <meta property="og:image" content="http://y.com/b.jpg" />
+        <meta property="og:audio" content="/a.mp3" />
+    </head>"#;
+
+    #[test]
+    fn scrape_document() {
+        let url = Url::parse("http://x.com").unwrap();
+        let document = Html::parse_document(HTML);
+
+        let result = BorrowedOpenGraphObject::scrape_document(&url, &document);
+
+        assert!(matches!(result.title, Some(Cow::Borrowed("Fish & Chips"))));
+        let images = result.images.as_ref().unwrap();
+        assert!(matches!(images[0].url, Cow::Owned(_)));
+        assert_eq!(images[0].url, "http://x.com/a.jpg");
+        assert_eq!(images[0].width, Some(300));
+        assert!(matches!(images[1].url, Cow::Borrowed("http://y.com/b.jpg")));
+        assert!(result.other.audio.is_some());
+    }
+
+    #[test]
+    fn into_owned() {
+        let url = Url::parse("http://x.com").unwrap();
+        let document = Html::parse_document(HTML);
+
+        let borrowed = BorrowedOpenGraphObject::scrape_document(&url, &document).into_owned();
+        let owned = OpenGraphScraper::scrape_document(&url, &document);
+
+        assert_eq!(
+            serde_json::to_value(borrowed).unwrap(),
+            serde_json::to_value(owned).unwrap()
+        );
+    }
+}
@@ -1,4 +1,7 @@
 pub mod property;
 
 mod open_graph_object;
-pub use open_graph_object::OpenGraphObject;
\ No newline at end of file
+pub use open_graph_object::OpenGraphObject;
+
+mod borrowed;
+pub use borrowed::{BorrowedImage, BorrowedOpenGraphObject};
@@ -4,6 +4,7 @@ use std::str::FromStr;
 use super::property::{Audio, Determiner, Image, Locale, Video};
 use crate::{
     error::ParseError,
+    product::property::Price,
     graph_object::{Update, GraphObject},
     meta_data::MetaData,
 };
@@ -20,6 +21,9 @@ pub struct OpenGraphObject {
     pub audio: Option<Vec<Audio>>,
     pub video: Option<Vec<Video>>,
     pub locale: Option<Locale>,
+    pub see_also: Option<Vec<String>>,
+    /// Legacy `og:price`, superseded by `product:price`.
+    pub prices: Option<Vec<Price>>,
 }
 
 impl GraphObject for OpenGraphObject {
@@ -44,6 +48,19 @@ impl GraphObject for OpenGraphObject {
                 return Ok(());
             }
 
+            if *first_tag == Price::prefix() {
+                let data = data.next();
+                // Push before parsing, so an invalid amount still takes the slot its
+                // `:currency` attaches to.
+                if Price::should_create_new(data.tags) {
+                    self.prices.get_or_insert(vec![]).push(Price::default());
+                }
+                if let Some(price) = self.prices.as_mut().and_then(|prices| prices.last_mut()) {
+                    price.update_from(data)?;
+                }
+                return Ok(());
+            }
+
             match *first_tag {
                 "title" => {
                     self.title = Some(data.content.into());
@@ -68,6 +85,10 @@ impl GraphObject for OpenGraphObject {
                 "locale" => {
                     self.locale = Some(Locale::new(data.content.into()));
                 }
+                "see_also" => {
+                    let see_also = self.see_also.get_or_insert_with(Vec::new);
+                    see_also.push(data.normalized_url());
+                }
                 _ => return Err(ParseError::InvalidPropertyTag),
             }
         }
@@ -28,7 +28,7 @@ impl GraphObject for Locale {
                 self.locale = data.content.into();
             }
             ["alternate"] => {
-                let alternate = self.alternate.get_or_insert_with(|| vec![]);
+                let alternate = self.alternate.get_or_insert_with(Vec::new);
                 alternate.push(data.content.into());
             }
             _ => return Err(ParseError::InvalidPropertyTag),
@@ -0,0 +1,4 @@
+pub mod property;
+
+mod platform_object;
+pub use platform_object::{PlatformMeta, PlatformScraper};
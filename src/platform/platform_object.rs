@@ -0,0 +1,149 @@
+use scraper::{Html, Selector};
+use serde::Serialize;
+use std::{error::Error, sync::LazyLock};
+use url::Url;
+
+use super::property::{FormatDetection, ItunesApp, MsApplicationConfig};
+use crate::{error::ParseError, meta_data::MetaData};
+
+/// Apple and Microsoft platform meta tags.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct PlatformMeta {
+    pub itunes_app: Option<ItunesApp>,
+    pub apple_mobile_web_app_title: Option<String>,
+    pub apple_mobile_web_app_capable: Option<bool>,
+    pub application_name: Option<String>,
+    pub msapplication_tile_color: Option<String>,
+    pub msapplication_config: Option<MsApplicationConfig>,
+    pub format_detection: Option<FormatDetection>,
+}
+
+impl PlatformMeta {
+    fn update_from(&mut self, name: &str, data: MetaData) -> Result<(), ParseError> {
+        match name.to_ascii_lowercase().as_str() {
+            "apple-itunes-app" => {
+                self.itunes_app = Some(ItunesApp::parse(data.content)?);
+            }
+            "apple-mobile-web-app-title" => {
+                self.apple_mobile_web_app_title = Some(data.content.into());
+            }
+            "apple-mobile-web-app-capable" | "mobile-web-app-capable" => {
+                self.apple_mobile_web_app_capable = Some(data.content.eq_ignore_ascii_case("yes"));
+            }
+            "application-name" => {
+                self.application_name = Some(data.content.into());
+            }
+            "msapplication-tilecolor" => {
+                self.msapplication_tile_color = Some(data.content.into());
+            }
+            "msapplication-config" => {
+                let config = match data.content.eq_ignore_ascii_case("none") {
+                    true => MsApplicationConfig::None,
+                    false => MsApplicationConfig::Url(data.normalized_url()),
+                };
+                self.msapplication_config = Some(config);
+            }
+            "format-detection" => {
+                self.format_detection = Some(FormatDetection::parse(data.content));
+            }
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+}
+
+pub struct PlatformScraper {}
+
+impl PlatformScraper {
+    /// Get the CSS selector for named meta elements.
+    fn selector() -> &'static Selector {
+        static SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("head > meta[name]").unwrap());
+        &SELECTOR
+    }
+
+    /// Scrape the document for platform meta tags.
+    pub fn scrape(url: &str, html: &str) -> Result<PlatformMeta, Box<dyn Error>> {
+        // Parsing validates the supplied url.
+        let url = Url::parse(url)?;
+
+        let document = Html::parse_document(html);
+        let mut result = PlatformMeta::default();
+
+        for element in document.select(Self::selector()) {
+            let name = element.value().attr("name");
+            let content = element.value().attr("content");
+
+            if let (Some(name), Some(content)) = (name, content) {
+                let meta_data = MetaData {
+                    site_url: &url,
+                    tags: &[],
+                    content: content.trim(),
+                };
+
+                let _ = result.update_from(name, meta_data);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scrape() {
+        let result = PlatformScraper::scrape(
+            "http://x.com",
+            r##"<head>
+                <meta name="apple-itunes-app" content="app-id=123, affiliate-data=x" />
+                <meta name="apple-mobile-web-app-title" content="X" />
+                <meta name="apple-mobile-web-app-capable" content="yes" />
+                <meta name="application-name" content="X App" />
+                <meta name="msapplication-TileColor" content="#ffffff" />
+                <meta name="msapplication-config" content="/browserconfig.xml" />
+                <meta name="format-detection" content="telephone=no" />
+            </head>"##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            result,
+            PlatformMeta {
+                itunes_app: Some(ItunesApp {
+                    app_id: "123".into(),
+                    affiliate_data: Some("x".into()),
+                    app_argument: None,
+                }),
+                apple_mobile_web_app_title: Some("X".into()),
+                apple_mobile_web_app_capable: Some(true),
+                application_name: Some("X App".into()),
+                msapplication_tile_color: Some("#ffffff".into()),
+                msapplication_config: Some(MsApplicationConfig::Url(
+                    "http://x.com/browserconfig.xml".into()
+                )),
+                format_detection: Some(FormatDetection {
+                    telephone: Some(false),
+                    ..FormatDetection::default()
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn scrape_msapplication_config_none() {
+        let result = PlatformScraper::scrape(
+            "http://x.com",
+            r#"<head><meta name="msapplication-config" content="None" /></head>"#,
+        )
+        .unwrap();
+
+        assert_eq!(result.msapplication_config, Some(MsApplicationConfig::None));
+        assert_eq!(
+            serde_json::to_value(&result).unwrap()["msapplication_config"],
+            "none"
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use serde::Serialize;
+
+use super::parse_pairs;
+
+/// Which formats iOS Safari may turn into links, from `<meta name="format-detection">`.
+///
+/// Each is `None` when unspecified, in which case detection is on.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct FormatDetection {
+    pub telephone: Option<bool>,
+    pub date: Option<bool>,
+    pub address: Option<bool>,
+    pub email: Option<bool>,
+}
+
+impl FormatDetection {
+    pub fn parse(content: &str) -> Self {
+        let mut format_detection = Self::default();
+
+        for (key, value) in parse_pairs(content, &["telephone", "date", "address", "email"]) {
+            let enabled = match value.to_ascii_lowercase().as_str() {
+                "yes" => true,
+                "no" => false,
+                _ => continue,
+            };
+            match key.to_ascii_lowercase().as_str() {
+                "telephone" => format_detection.telephone = Some(enabled),
+                "date" => format_detection.date = Some(enabled),
+                "address" => format_detection.address = Some(enabled),
+                _ => format_detection.email = Some(enabled),
+            }
+        }
+        format_detection
+    }
+}
@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+use super::parse_pairs;
+use crate::error::ParseError;
+
+/// The Safari smart app banner, from `<meta name="apple-itunes-app">`.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct ItunesApp {
+    pub app_id: String,
+    pub affiliate_data: Option<String>,
+    pub app_argument: Option<String>,
+}
+
+impl ItunesApp {
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut app_id = None;
+        let mut itunes_app = Self::default();
+
+        for (key, value) in parse_pairs(content, &["app-id", "affiliate-data", "app-argument"]) {
+            match key.to_ascii_lowercase().as_str() {
+                "app-id" => app_id = Some(value),
+                "affiliate-data" => itunes_app.affiliate_data = Some(value),
+                _ => itunes_app.app_argument = Some(value),
+            }
+        }
+
+        // The banner is only shown with an app id.
+        itunes_app.app_id = app_id
+            .filter(|app_id| !app_id.is_empty())
+            .ok_or_else(|| ParseError::InvalidContent(content.into()))?;
+        Ok(itunes_app)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            ItunesApp::parse("app-id=123, app-argument=https://x.com/a?b=1,2").unwrap(),
+            ItunesApp {
+                app_id: "123".into(),
+                affiliate_data: None,
+                app_argument: Some("https://x.com/a?b=1,2".into()),
+            }
+        );
+        assert!(ItunesApp::parse("affiliate-data=x").is_err());
+    }
+}
@@ -0,0 +1,46 @@
+mod itunes_app;
+pub use itunes_app::ItunesApp;
+
+mod format_detection;
+pub use format_detection::FormatDetection;
+
+mod ms_application_config;
+pub use ms_application_config::MsApplicationConfig;
+
+/// Split `key=value` pairs separated by commas, e.g. `telephone=no, date=no`.
+///
+/// Segments without a known key are joined to the previous value, so values
+/// such as urls may contain commas.
+pub(crate) fn parse_pairs<'a>(content: &'a str, keys: &[&str]) -> Vec<(&'a str, String)> {
+    let mut pairs: Vec<(&str, String)> = vec![];
+
+    for segment in content.split(',') {
+        let key = segment
+            .split_once('=')
+            .map(|(key, value)| (key.trim(), value))
+            .filter(|(key, _)| keys.iter().any(|known| known.eq_ignore_ascii_case(key)));
+
+        match (key, pairs.last_mut()) {
+            (Some((key, value)), _) => pairs.push((key, value.trim().into())),
+            (None, Some((_, value))) => {
+                value.push(',');
+                value.push_str(segment.trim_end());
+            }
+            (None, None) => {}
+        }
+    }
+    pairs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pairs() {
+        assert_eq!(
+            parse_pairs("a=1, b=x://y?c=1,2, unknown=3", &["a", "b"]),
+            vec![("a", "1".into()), ("b", "x://y?c=1,2, unknown=3".into())]
+        );
+    }
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+/// The browserconfig.xml for Windows tiles, from `<meta name="msapplication-config">`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MsApplicationConfig {
+    /// `none`, opting out of the default `/browserconfig.xml` lookup.
+    None,
+    /// The url, resolved against the site url.
+    Url(String),
+}
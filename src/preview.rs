@@ -0,0 +1,140 @@
+use scraper::Html;
+use serde::Serialize;
+use std::error::Error;
+use url::Url;
+
+use crate::{
+    microformats::{Mf2Document, MicroformatsScraper},
+    open_graph::OpenGraphObject,
+    scraper::{OpenGraphScraper, Scraper, TwitterScraper},
+    twitter::TwitterGraphObject,
+};
+
+/// A link preview merged from Open Graph, with Twitter cards and then a
+/// microformats `h-entry` as fallbacks.
+#[derive(Default, Debug, Serialize)]
+pub struct Preview {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub url: Option<String>,
+    pub image: Option<String>,
+    pub site_name: Option<String>,
+    pub open_graph: OpenGraphObject,
+    pub twitter: TwitterGraphObject,
+}
+
+impl Preview {
+    /// Merge the preview, preferring Open Graph over the Twitter card.
+    pub fn from_sources(open_graph: OpenGraphObject, twitter: TwitterGraphObject) -> Self {
+        let image = open_graph
+            .images
+            .as_ref()
+            .and_then(|images| images.first())
+            .map(|image| image.url.clone());
+
+        Self {
+            title: open_graph.title.clone().or_else(|| twitter.title.clone()),
+            description: open_graph
+                .description
+                .clone()
+                .or_else(|| twitter.description.clone()),
+            url: open_graph.url.clone(),
+            image: image.or_else(|| twitter.image.as_ref().map(|image| image.url.clone())),
+            site_name: open_graph.site_name.clone(),
+            open_graph,
+            twitter,
+        }
+    }
+
+    /// Whether any field could still be filled from microformats.
+    fn is_incomplete(&self) -> bool {
+        self.title.is_none()
+            || self.description.is_none()
+            || self.url.is_none()
+            || self.image.is_none()
+    }
+
+    /// Fill the fields Open Graph and Twitter left empty from the first `h-entry`.
+    pub fn fill_from_microformats(&mut self, microformats: &Mf2Document) {
+        let Some(entry) = microformats.find("h-entry") else {
+            return;
+        };
+        let text = |name| entry.first_text(name).map(String::from);
+
+        self.title = self.title.take().or_else(|| text("name"));
+        self.description = self.description.take().or_else(|| text("summary"));
+        self.url = self.url.take().or_else(|| text("url"));
+        self.image = self.image.take().or_else(|| text("photo"));
+    }
+}
+
+pub struct PreviewScraper {}
+
+impl PreviewScraper {
+    /// Scrape the document for a link preview.
+    pub fn scrape(url: &str, html: &str) -> Result<Preview, Box<dyn Error>> {
+        // Parsing validates the supplied url.
+        let url = Url::parse(url)?;
+
+        let head = OpenGraphScraper::extract_head(html);
+        let document = Html::parse_document(head);
+
+        let mut preview = Preview::from_sources(
+            OpenGraphScraper::scrape_document(&url, &document),
+            TwitterScraper::scrape_document(&url, &document),
+        );
+
+        // Entries are in the body, so only parse the whole page when needed.
+        if preview.is_incomplete() {
+            let document = Html::parse_document(html);
+            preview.fill_from_microformats(&MicroformatsScraper::parse(&url, &document));
+        }
+        Ok(preview)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scrape() {
+        let preview = PreviewScraper::scrape(
+            "http://x.com",
+            r#"<head>
+                <meta property="og:title" content="OG" />
+                <meta name="twitter:title" content="Twitter" />
+                <meta name="twitter:description" content="Description" />
+                <meta name="twitter:image" content="/a.jpg" />
+            </head>"#,
+        )
+        .unwrap();
+
+        assert_eq!(preview.title, Some("OG".into()));
+        assert_eq!(preview.description, Some("Description".into()));
+        assert_eq!(preview.image, Some("http://x.com/a.jpg".into()));
+        assert_eq!(preview.url, None);
+    }
+
+    #[test]
+    fn scrape_entry_fallback() {
+        let preview = PreviewScraper::scrape(
+            "http://x.com",
+            r#"<head><meta property="og:title" content="OG" /></head>
+            <body>
+                <article class="h-entry">
+                    <h1 class="p-name">Entry</h1>
+                    <p class="p-summary">Summary</p>
+                    <a class="u-url" href="/post">Permalink</a>
+                    <img class="u-photo" src="/a.jpg" />
+                </article>
+            </body>"#,
+        )
+        .unwrap();
+
+        assert_eq!(preview.title, Some("OG".into()));
+        assert_eq!(preview.description, Some("Summary".into()));
+        assert_eq!(preview.url, Some("http://x.com/post".into()));
+        assert_eq!(preview.image, Some("http://x.com/a.jpg".into()));
+    }
+}
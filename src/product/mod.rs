@@ -0,0 +1,4 @@
+pub mod property;
+
+mod product_object;
+pub use product_object::ProductObject;
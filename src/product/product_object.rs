@@ -0,0 +1,74 @@
+use serde::Serialize;
+use std::str::FromStr;
+
+use super::property::{Availability, Condition, Price};
+use crate::{
+    error::ParseError,
+    graph_object::GraphObject,
+    meta_data::MetaData,
+};
+
+/// Product properties, `product:*`.
+#[derive(Default, Debug, Serialize)]
+pub struct ProductObject {
+    pub prices: Option<Vec<Price>>,
+    pub sale_price: Option<Price>,
+    pub original_price: Option<Price>,
+    pub availability: Option<Availability>,
+    pub condition: Option<Condition>,
+    pub retailer_item_id: Option<String>,
+    pub brand: Option<String>,
+}
+
+impl GraphObject for ProductObject {
+    fn prefix() -> &'static str {
+        "product"
+    }
+
+    fn update_from(&mut self, data: MetaData) -> Result<(), ParseError> {
+        if let Some(first_tag) = data.tags.first() {
+            if *first_tag == Price::prefix() {
+                let data = data.next();
+                // Push before parsing, so an invalid amount still takes the slot its
+                // `:currency` attaches to.
+                if Price::should_create_new(data.tags) {
+                    self.prices.get_or_insert(vec![]).push(Price::default());
+                }
+                if let Some(price) = self.prices.as_mut().and_then(|prices| prices.last_mut()) {
+                    price.update_from(data)?;
+                }
+                return Ok(());
+            }
+
+            match *first_tag {
+                "sale_price" => {
+                    let price = self.sale_price.get_or_insert(Price::default());
+                    price.update_from(data.next())?;
+                }
+                "original_price" => {
+                    let price = self.original_price.get_or_insert(Price::default());
+                    price.update_from(data.next())?;
+                }
+                "availability" => {
+                    let availability = Availability::from_str(data.content.trim())
+                        .map_err(|_| ParseError::InvalidContent(data.content.into()))?;
+                    self.availability = Some(availability);
+                }
+                "condition" => {
+                    let condition = Condition::from_str(data.content.trim())
+                        .map_err(|_| ParseError::InvalidContent(data.content.into()))?;
+                    self.condition = Some(condition);
+                }
+                "retailer_item_id" => {
+                    self.retailer_item_id = Some(data.content.into());
+                }
+                "brand" => {
+                    self.brand = Some(data.content.into());
+                }
+                _ => return Err(ParseError::InvalidPropertyTag),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Serialize, Serializer};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use crate::error::ParseError;
+
+/// A non-negative decimal amount, held exactly as integer units at a given scale.
+///
+/// `12.50` is stored as `1250` units at scale `2`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Amount {
+    units: u64,
+    scale: u32,
+}
+
+impl Amount {
+    pub fn new(units: u64, scale: u32) -> Self {
+        Self { units, scale }
+    }
+
+    pub fn units(&self) -> u64 {
+        self.units
+    }
+
+    /// The number of digits after the decimal point.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+}
+
+impl FromStr for Amount {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidContent(s.into());
+
+        let (whole, fraction) = s.trim().split_once('.').unwrap_or((s.trim(), ""));
+        let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+
+        if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+            return Err(invalid());
+        }
+
+        let scale = u32::try_from(fraction.len()).map_err(|_| invalid())?;
+        let units = format!("{}{}", whole, fraction)
+            .parse()
+            .map_err(|_| invalid())?;
+
+        Ok(Self { units, scale })
+    }
+}
+
+impl Display for Amount {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let digits = format!("{:0>width$}", self.units, width = self.scale as usize + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - self.scale as usize);
+
+        if fraction.is_empty() {
+            write!(f, "{}", whole)
+        } else {
+            write!(f, "{}.{}", whole, fraction)
+        }
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn amount_from_str() {
+        assert_eq!(Amount::from_str("12.50").unwrap(), Amount::new(1250, 2));
+        assert_eq!(Amount::from_str(" 3 ").unwrap(), Amount::new(3, 0));
+        assert_eq!(Amount::from_str(".5").unwrap(), Amount::new(5, 1));
+        assert!(Amount::from_str("").is_err());
+        assert!(Amount::from_str(".").is_err());
+        assert!(Amount::from_str("-1").is_err());
+        assert!(Amount::from_str("1,000").is_err());
+        assert!(Amount::from_str("1.2.3").is_err());
+    }
+
+    #[test]
+    fn amount_display() {
+        assert_eq!(Amount::new(1250, 2).to_string(), "12.50");
+        assert_eq!(Amount::new(5, 2).to_string(), "0.05");
+        assert_eq!(Amount::new(3, 0).to_string(), "3");
+    }
+}
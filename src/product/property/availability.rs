@@ -0,0 +1,42 @@
+use serde::Serialize;
+use strum::EnumString;
+
+#[derive(Debug, PartialEq, EnumString, Serialize)]
+#[strum(ascii_case_insensitive)]
+#[serde(rename_all = "snake_case")]
+pub enum Availability {
+    #[strum(serialize = "in stock", serialize = "instock", serialize = "in_stock")]
+    InStock,
+    #[strum(
+        serialize = "out of stock",
+        serialize = "oos",
+        serialize = "out_of_stock"
+    )]
+    OutOfStock,
+    #[strum(serialize = "preorder", serialize = "pre-order")]
+    Preorder,
+    #[strum(serialize = "available for order", serialize = "available_for_order")]
+    AvailableForOrder,
+    #[strum(serialize = "discontinued")]
+    Discontinued,
+    #[strum(serialize = "pending")]
+    Pending,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn availability_from_str() {
+        assert_eq!(
+            Availability::from_str("in stock").unwrap(),
+            Availability::InStock
+        );
+        assert_eq!(
+            Availability::from_str("OOS").unwrap(),
+            Availability::OutOfStock
+        );
+    }
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+use strum::EnumString;
+
+#[derive(Debug, PartialEq, EnumString, Serialize)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+#[serde(rename_all = "snake_case")]
+pub enum Condition {
+    New,
+    Refurbished,
+    Used,
+}
@@ -0,0 +1,14 @@
+mod amount;
+pub use amount::Amount;
+
+mod currency;
+pub use currency::Currency;
+
+mod price;
+pub use price::Price;
+
+mod availability;
+pub use availability::Availability;
+
+mod condition;
+pub use condition::Condition;
@@ -0,0 +1,35 @@
+use serde::Serialize;
+use std::str::FromStr;
+
+use super::{Amount, Currency};
+use crate::{error::ParseError, graph_object::GraphObject, meta_data::MetaData};
+
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct Price {
+    /// Unset when only a currency is given or the amount is invalid.
+    pub amount: Option<Amount>,
+    pub currency: Option<Currency>,
+}
+
+impl GraphObject for Price {
+    fn prefix() -> &'static str {
+        "price"
+    }
+
+    fn should_create_new(tags: &[&str]) -> bool {
+        tags == ["amount"]
+    }
+
+    fn update_from(&mut self, data: MetaData) -> Result<(), ParseError> {
+        match data.tags {
+            ["amount"] => {
+                self.amount = Some(Amount::from_str(data.content)?);
+            }
+            ["currency"] => {
+                self.currency = Some(Currency::from_str(data.content)?);
+            }
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+}
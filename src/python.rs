@@ -0,0 +1,215 @@
+//! The `metagraph` Python module, returning scraped metadata as dicts.
+//!
+//! Parsing runs with the GIL released, and batches are scraped in parallel.
+
+// The `pyfunction` macro converts errors into `PyErr`, even when they already are.
+#![allow(clippy::useless_conversion)]
+
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyDict, PyList},
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{batch::scrape_batch, OpenGraphScraper, PreviewScraper, Scraper, TwitterScraper};
+
+/// Convert JSON to the equivalent Python objects.
+fn to_python(py: Python<'_>, value: &Value) -> PyResult<PyObject> {
+    let object = match value {
+        Value::Null => py.None(),
+        Value::Bool(value) => value.into_py(py),
+        Value::Number(number) => match (number.as_i64(), number.as_u64()) {
+            (Some(number), _) => number.into_py(py),
+            (_, Some(number)) => number.into_py(py),
+            _ => number.as_f64().into_py(py),
+        },
+        Value::String(value) => value.into_py(py),
+        Value::Array(values) => {
+            let values = values
+                .iter()
+                .map(|value| to_python(py, value))
+                .collect::<PyResult<Vec<_>>>()?;
+            PyList::new_bound(py, values).into_py(py)
+        }
+        Value::Object(object) => {
+            let dict = PyDict::new_bound(py);
+            for (key, value) in object {
+                dict.set_item(key, to_python(py, value)?)?;
+            }
+            dict.into_py(py)
+        }
+    };
+    Ok(object)
+}
+
+fn to_value(result: Result<impl Serialize, Box<dyn std::error::Error>>) -> Result<Value, String> {
+    let object = result.map_err(|error| error.to_string())?;
+    serde_json::to_value(object).map_err(|error| error.to_string())
+}
+
+/// Scrape with the GIL released, raising `ValueError` on failure.
+fn scrape_with<F>(py: Python<'_>, scrape: F) -> PyResult<PyObject>
+where
+    F: Send + FnOnce() -> Result<Value, String>,
+{
+    let value = py.allow_threads(scrape).map_err(PyValueError::new_err)?;
+    to_python(py, &value)
+}
+
+/// Scrape pages in parallel with the GIL released, with `None` for pages which failed.
+fn scrape_batch_with<S>(py: Python<'_>, pages: Vec<(String, String)>) -> PyResult<Vec<PyObject>>
+where
+    S: Scraper,
+    S::RootGraphObject: Send + Serialize,
+{
+    let values: Vec<Option<Value>> = py.allow_threads(|| {
+        scrape_batch::<S, _, _, _>(pages)
+            .map(|result| {
+                result
+                    .ok()
+                    .and_then(|object| serde_json::to_value(object).ok())
+            })
+            .collect()
+    });
+
+    values
+        .iter()
+        .map(|value| match value {
+            Some(value) => to_python(py, value),
+            None => Ok(py.None()),
+        })
+        .collect()
+}
+
+/// Scrape a link preview, merged from Open Graph and the Twitter card.
+#[pyfunction]
+fn scrape(py: Python<'_>, url: &str, html: &str) -> PyResult<PyObject> {
+    scrape_with(py, || to_value(PreviewScraper::scrape(url, html)))
+}
+
+/// Scrape Open Graph metadata.
+#[pyfunction]
+fn scrape_open_graph(py: Python<'_>, url: &str, html: &str) -> PyResult<PyObject> {
+    scrape_with(py, || to_value(OpenGraphScraper::scrape(url, html)))
+}
+
+/// Scrape Twitter card metadata.
+#[pyfunction]
+fn scrape_twitter(py: Python<'_>, url: &str, html: &str) -> PyResult<PyObject> {
+    scrape_with(py, || to_value(TwitterScraper::scrape(url, html)))
+}
+
+/// Scrape Open Graph metadata from `(url, html)` pairs, with `None` for invalid urls.
+#[pyfunction]
+fn scrape_open_graph_batch(
+    py: Python<'_>,
+    pages: Vec<(String, String)>,
+) -> PyResult<Vec<PyObject>> {
+    scrape_batch_with::<OpenGraphScraper>(py, pages)
+}
+
+/// Scrape Twitter card metadata from `(url, html)` pairs, with `None` for invalid urls.
+#[pyfunction]
+fn scrape_twitter_batch(py: Python<'_>, pages: Vec<(String, String)>) -> PyResult<Vec<PyObject>> {
+    scrape_batch_with::<TwitterScraper>(py, pages)
+}
+
+#[pymodule]
+fn metagraph(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(scrape, module)?)?;
+    module.add_function(wrap_pyfunction!(scrape_open_graph, module)?)?;
+    module.add_function(wrap_pyfunction!(scrape_twitter, module)?)?;
+    module.add_function(wrap_pyfunction!(scrape_open_graph_batch, module)?)?;
+    module.add_function(wrap_pyfunction!(scrape_twitter_batch, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const HTML: &str = r#"<head>
+        <meta property="og:title" content="Title" />
+        <meta property="og:image" content="/a.jpg" />
+        <meta property="og:image:width" content="300" />
+    </head>"#;
+
+    fn with_module(test: impl FnOnce(Python<'_>, &Bound<'_, PyModule>)) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new_bound(py, "metagraph").unwrap();
+            metagraph(&module).unwrap();
+            test(py, &module);
+        });
+    }
+
+    #[test]
+    fn scrape_open_graph() {
+        with_module(|_, module| {
+            let result = module
+                .getattr("scrape_open_graph")
+                .unwrap()
+                .call1(("http://x.com", HTML))
+                .unwrap();
+            let result = result.downcast::<PyDict>().unwrap();
+            let image = result
+                .get_item("images")
+                .unwrap()
+                .unwrap()
+                .get_item(0)
+                .unwrap();
+
+            let title: String = result
+                .get_item("title")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            let url: String = image.get_item("url").unwrap().extract().unwrap();
+            let width: u32 = image.get_item("width").unwrap().extract().unwrap();
+            assert_eq!(title, "Title");
+            assert_eq!(url, "http://x.com/a.jpg");
+            assert_eq!(width, 300);
+            assert!(result.get_item("description").unwrap().unwrap().is_none());
+        });
+    }
+
+    #[test]
+    fn scrape_invalid_url() {
+        with_module(|py, module| {
+            let error = module
+                .getattr("scrape")
+                .unwrap()
+                .call1(("not a url", HTML))
+                .unwrap_err();
+
+            assert!(error.is_instance_of::<PyValueError>(py));
+        });
+    }
+
+    #[test]
+    fn scrape_batch() {
+        with_module(|_, module| {
+            let pages = vec![("http://x.com", HTML), ("not a url", HTML)];
+            let results = module
+                .getattr("scrape_open_graph_batch")
+                .unwrap()
+                .call1((pages,))
+                .unwrap();
+
+            let results: Vec<Option<Bound<'_, PyDict>>> = results.extract().unwrap();
+            let title: String = results[0]
+                .as_ref()
+                .unwrap()
+                .get_item("title")
+                .unwrap()
+                .unwrap()
+                .extract()
+                .unwrap();
+            assert_eq!(title, "Title");
+            assert!(results[1].is_none());
+        });
+    }
+}
@@ -0,0 +1,321 @@
+use scraper::{ElementRef, Html};
+use std::{collections::HashMap, error::Error};
+use url::Url;
+
+use super::{graph::RDF_TYPE, Graph, Literal, Term, Triple};
+use crate::meta_data::MetaData;
+
+/// The predicate linking a document to a vocabulary it uses.
+const USES_VOCABULARY: &str = "http://www.w3.org/ns/rdfa#usesVocabulary";
+
+/// Prefixes from the RDFa 1.1 initial context, most relevant to web pages.
+const INITIAL_PREFIXES: &[(&str, &str)] = &[
+    ("as", "https://www.w3.org/ns/activitystreams#"),
+    ("cc", "http://creativecommons.org/ns#"),
+    ("dc", "http://purl.org/dc/terms/"),
+    ("dc11", "http://purl.org/dc/elements/1.1/"),
+    ("dcterms", "http://purl.org/dc/terms/"),
+    ("foaf", "http://xmlns.com/foaf/0.1/"),
+    ("gr", "http://purl.org/goodrelations/v1#"),
+    ("og", "http://ogp.me/ns#"),
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfa", "http://www.w3.org/ns/rdfa#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("schema", "http://schema.org/"),
+    ("sioc", "http://rdfs.org/sioc/ns#"),
+    ("skos", "http://www.w3.org/2004/02/skos/core#"),
+    ("vcard", "http://www.w3.org/2006/vcard/ns#"),
+    ("void", "http://rdfs.org/ns/void#"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+];
+
+/// Extracts RDFa Lite 1.1 triples from `vocab`, `prefix`, `typeof`, `property`
+/// and `resource` attributes anywhere in a document.
+pub struct RdfaScraper {}
+
+/// The evaluation context inherited by child elements.
+#[derive(Clone)]
+struct Context {
+    subject: Term,
+    vocab: Option<String>,
+    prefixes: HashMap<String, String>,
+    language: Option<String>,
+}
+
+struct Extractor<'a> {
+    site_url: &'a Url,
+    graph: Graph,
+    blank_nodes: usize,
+}
+
+impl<'a> Extractor<'a> {
+    fn new_blank_node(&mut self) -> Term {
+        let label = format!("b{}", self.blank_nodes);
+        self.blank_nodes += 1;
+        Term::Blank(label)
+    }
+
+    fn url(&self, content: &str) -> String {
+        let meta_data = MetaData {
+            site_url: self.site_url,
+            tags: &[],
+            content: content.trim(),
+        };
+        meta_data.normalized_url()
+    }
+
+    fn push(&mut self, subject: Term, predicate: String, object: Term) {
+        self.graph.triples.push(Triple {
+            subject,
+            predicate,
+            object,
+        });
+    }
+
+    /// Resolve a `property` or `typeof` value: a term, CURIE or absolute IRI.
+    fn resolve_term(context: &Context, value: &str) -> Option<String> {
+        if let Some((prefix, reference)) = value.split_once(':') {
+            if let Some(namespace) = context.prefixes.get(&prefix.to_ascii_lowercase()) {
+                return Some(format!("{}{}", namespace, reference));
+            }
+            // Blank node CURIEs are not valid predicates or types.
+            if prefix == "_" {
+                return None;
+            }
+            return Url::parse(value).ok().map(String::from);
+        }
+        context
+            .vocab
+            .as_ref()
+            .map(|vocab| format!("{}{}", vocab, value))
+    }
+
+    /// Resolve a `resource`, `href` or `src` value to a subject or object.
+    fn resolve_resource(&self, context: &Context, value: &str) -> Term {
+        let value = value.trim();
+
+        // Safe CURIEs, e.g. `[schema:Person]` or `[_:a]`.
+        if let Some(curie) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+            if let Some(label) = curie.strip_prefix("_:") {
+                return Term::Blank(format!("n-{}", label));
+            }
+            if let Some(iri) = Self::resolve_term(context, curie) {
+                return Term::Iri(iri);
+            }
+        }
+        if let Some(label) = value.strip_prefix("_:") {
+            return Term::Blank(format!("n-{}", label));
+        }
+        Term::Iri(self.url(value))
+    }
+
+    fn resource(&self, context: &Context, element: &ElementRef) -> Option<Term> {
+        let value = element.value();
+        value
+            .attr("resource")
+            .or_else(|| value.attr("href"))
+            .or_else(|| value.attr("src"))
+            .map(|resource| self.resolve_resource(context, resource))
+    }
+
+    fn literal(context: &Context, element: &ElementRef) -> Term {
+        let value = element.value();
+        let content = value
+            .attr("content")
+            .or_else(|| {
+                (value.name() == "time")
+                    .then(|| value.attr("datetime"))
+                    .flatten()
+            })
+            .map(String::from)
+            .unwrap_or_else(|| element.text().collect());
+
+        Term::Literal(Literal {
+            value: content,
+            language: context.language.clone(),
+        })
+    }
+
+    fn element(&mut self, element: ElementRef, parent: &Context) {
+        let value = element.value();
+        let mut context = parent.clone();
+
+        if let Some(vocab) = value.attr("vocab") {
+            let vocab = vocab.trim();
+            if vocab.is_empty() {
+                context.vocab = None;
+            } else {
+                let vocab = self.url(vocab);
+                self.push(
+                    Term::Iri(self.site_url.to_string()),
+                    USES_VOCABULARY.into(),
+                    Term::Iri(vocab.clone()),
+                );
+                context.vocab = Some(vocab);
+            }
+        }
+
+        if let Some(prefixes) = value.attr("prefix") {
+            let mut tokens = prefixes.split_whitespace();
+            while let (Some(prefix), Some(namespace)) = (tokens.next(), tokens.next()) {
+                if let Some(prefix) = prefix.strip_suffix(':') {
+                    context
+                        .prefixes
+                        .insert(prefix.to_ascii_lowercase(), namespace.into());
+                }
+            }
+        }
+
+        if let Some(language) = value.attr("lang").or_else(|| value.attr("xml:lang")) {
+            context.language =
+                Some(language.into()).filter(|language: &String| !language.is_empty());
+        }
+
+        let types: Vec<String> = value
+            .attr("typeof")
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|value| Self::resolve_term(&context, value))
+            .collect();
+        let properties: Vec<String> = value
+            .attr("property")
+            .unwrap_or_default()
+            .split_whitespace()
+            .filter_map(|value| Self::resolve_term(&context, value))
+            .collect();
+        let has_typeof = value.attr("typeof").is_some();
+        let has_property = value.attr("property").is_some();
+
+        // A typed resource, which is the object of any property and the subject of children.
+        let typed_resource = has_typeof.then(|| {
+            self.resource(&context, &element)
+                .unwrap_or_else(|| self.new_blank_node())
+        });
+
+        if let Some(resource) = &typed_resource {
+            for item_type in types {
+                self.push(resource.clone(), RDF_TYPE.into(), Term::Iri(item_type));
+            }
+        }
+
+        if has_property {
+            let object = match &typed_resource {
+                Some(resource) => resource.clone(),
+                None => match value.attr("resource") {
+                    Some(resource) => self.resolve_resource(&context, resource),
+                    None if value.attr("content").is_none() => self
+                        .resource(&context, &element)
+                        .unwrap_or_else(|| Self::literal(&context, &element)),
+                    None => Self::literal(&context, &element),
+                },
+            };
+            for property in properties {
+                self.push(context.subject.clone(), property, object.clone());
+            }
+        }
+
+        if let Some(resource) = typed_resource {
+            context.subject = resource;
+        } else if !has_property {
+            if let Some(resource) = self.resource(&context, &element) {
+                context.subject = resource;
+            }
+        }
+
+        for child in element.children().filter_map(ElementRef::wrap) {
+            self.element(child, &context);
+        }
+    }
+}
+
+impl RdfaScraper {
+    /// Scrape the whole document for RDFa triples.
+    pub fn scrape(url: &str, html: &str) -> Result<Graph, Box<dyn Error>> {
+        // Parsing validates the supplied url.
+        let url = Url::parse(url)?;
+
+        let document = Html::parse_document(html);
+
+        let context = Context {
+            subject: Term::Iri(url.to_string()),
+            vocab: None,
+            prefixes: INITIAL_PREFIXES
+                .iter()
+                .map(|(prefix, namespace)| (prefix.to_string(), namespace.to_string()))
+                .collect(),
+            language: None,
+        };
+        let mut extractor = Extractor {
+            site_url: &url,
+            graph: Graph::default(),
+            blank_nodes: 0,
+        };
+
+        extractor.element(document.root_element(), &context);
+
+        Ok(extractor.graph)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::*;
+
+    // Helper to scrape HTML string.
+    fn scrape(html: &str) -> Graph {
+        RdfaScraper::scrape("http://x.com/", html).unwrap()
+    }
+
+    #[test]
+    fn scrape_person() {
+        let graph = scrape(
+            r##"<body>
+                <div vocab="http://schema.org/" typeof="Person" resource="#me">
+                    <span property="name">Alice</span>
+                    <a property="url" href="/alice">Home</a>
+                    <div property="address" typeof="PostalAddress">
+                        <meta property="addressCountry" content="GB" />
+                    </div>
+                </div>
+            </body>"##,
+        );
+
+        assert_eq!(
+            graph.to_ntriples(),
+            concat!(
+                "<http://x.com/> <http://www.w3.org/ns/rdfa#usesVocabulary> <http://schema.org/> .\n",
+                "<http://x.com/#me> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://schema.org/Person> .\n",
+                "<http://x.com/#me> <http://schema.org/name> \"Alice\" .\n",
+                "<http://x.com/#me> <http://schema.org/url> <http://x.com/alice> .\n",
+                "_:b0 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://schema.org/PostalAddress> .\n",
+                "<http://x.com/#me> <http://schema.org/address> _:b0 .\n",
+                "_:b0 <http://schema.org/addressCountry> \"GB\" .\n",
+            )
+        );
+    }
+
+    #[test]
+    fn scrape_prefixes() {
+        let graph = scrape(
+            r#"<html prefix="ex: http://example.com/ns#" lang="en">
+                <head>
+                    <meta property="og:title" content="X" />
+                    <meta property="ex:rating" content="5" />
+                    <meta property="unmapped" content="ignored" />
+                </head>
+            </html>"#,
+        );
+
+        assert_eq!(
+            graph.to_json_ld(),
+            json!([{
+                "@id": "http://x.com/",
+                "http://ogp.me/ns#title": [{"@value": "X", "@language": "en"}],
+                "http://example.com/ns#rating": [{"@value": "5", "@language": "en"}],
+            }])
+        );
+    }
+}
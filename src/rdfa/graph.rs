@@ -0,0 +1,199 @@
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::fmt::{self, Display, Formatter};
+
+/// The IRI of the `rdf:type` predicate.
+pub const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+
+/// A subject or object of a triple.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Term {
+    Iri(String),
+    /// A blank node label, without the `_:` prefix.
+    Blank(String),
+    Literal(Literal),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Literal {
+    pub value: String,
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Triple {
+    pub subject: Term,
+    pub predicate: String,
+    pub object: Term,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct Graph {
+    pub triples: Vec<Triple>,
+}
+
+/// Escape a string for an N-Triples literal.
+fn escape_literal(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape a string for an N-Triples IRI, where only `\uXXXX` escapes are allowed.
+fn escape_iri(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\0'..=' ' | '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\' => {
+                escaped.push_str(&format!("\\u{:04X}", c as u32));
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl Display for Term {
+    /// Format the term as N-Triples.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Iri(iri) => write!(f, "<{}>", escape_iri(iri)),
+            Term::Blank(label) => write!(f, "_:{}", label),
+            Term::Literal(literal) => {
+                write!(f, "\"{}\"", escape_literal(&literal.value))?;
+                if let Some(language) = &literal.language {
+                    write!(f, "@{}", language)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Term {
+    /// The JSON-LD node identifier of an IRI or blank node.
+    fn json_ld_id(&self) -> Option<String> {
+        match self {
+            Term::Iri(iri) => Some(iri.clone()),
+            Term::Blank(label) => Some(format!("_:{}", label)),
+            Term::Literal(_) => None,
+        }
+    }
+
+    fn to_json_ld(&self) -> Value {
+        match self {
+            Term::Literal(Literal { value, language }) => match language {
+                Some(language) => json!({ "@value": value, "@language": language }),
+                None => json!({ "@value": value }),
+            },
+            term => json!({ "@id": term.json_ld_id() }),
+        }
+    }
+}
+
+impl Graph {
+    /// Serialize the graph as N-Triples, one triple per line.
+    pub fn to_ntriples(&self) -> String {
+        self.triples
+            .iter()
+            .map(|triple| {
+                format!(
+                    "{} <{}> {} .\n",
+                    triple.subject,
+                    escape_iri(&triple.predicate),
+                    triple.object
+                )
+            })
+            .collect()
+    }
+
+    /// Serialize the graph as flattened, expanded JSON-LD.
+    ///
+    /// Nodes appear in the order their first triple was found.
+    pub fn to_json_ld(&self) -> Value {
+        let mut nodes: Vec<(String, Map<String, Value>)> = vec![];
+
+        for triple in &self.triples {
+            let Some(id) = triple.subject.json_ld_id() else {
+                continue;
+            };
+            let index = match nodes.iter().position(|(existing, _)| *existing == id) {
+                Some(index) => index,
+                None => {
+                    let mut node = Map::new();
+                    node.insert("@id".into(), id.clone().into());
+                    nodes.push((id, node));
+                    nodes.len() - 1
+                }
+            };
+            let node = &mut nodes[index].1;
+
+            let (key, value) = match (&triple.object, triple.predicate.as_str()) {
+                (Term::Iri(_) | Term::Blank(_), RDF_TYPE) => {
+                    ("@type".into(), triple.object.json_ld_id().into())
+                }
+                (object, predicate) => (predicate.to_string(), object.to_json_ld()),
+            };
+
+            let values = node.entry(key).or_insert_with(|| Value::Array(vec![]));
+            if let Value::Array(values) = values {
+                values.push(value);
+            }
+        }
+
+        Value::Array(
+            nodes
+                .into_iter()
+                .map(|(_, node)| Value::Object(node))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ntriples() {
+        let graph = Graph {
+            triples: vec![Triple {
+                subject: Term::Blank("b0".into()),
+                predicate: "http://schema.org/name".into(),
+                object: Term::Literal(Literal {
+                    value: "A \"quoted\"\nname".into(),
+                    language: Some("en".into()),
+                }),
+            }],
+        };
+        assert_eq!(
+            graph.to_ntriples(),
+            "_:b0 <http://schema.org/name> \"A \\\"quoted\\\"\\nname\"@en .\n"
+        );
+    }
+
+    #[test]
+    fn ntriples_iri() {
+        let graph = Graph {
+            triples: vec![Triple {
+                subject: Term::Iri("http://x.com/a\"b c".into()),
+                predicate: "http://schema.org/url".into(),
+                object: Term::Iri("http://x.com/{x}|^`<>\\".into()),
+            }],
+        };
+        assert_eq!(
+            graph.to_ntriples(),
+            "<http://x.com/a\\u0022b\\u0020c> <http://schema.org/url> \
+             <http://x.com/\\u007Bx\\u007D\\u007C\\u005E\\u0060\\u003C\\u003E\\u005C> .\n"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod graph;
+pub use graph::{Graph, Literal, Term, Triple};
+
+mod extractor;
+pub use extractor::RdfaScraper;
@@ -0,0 +1,128 @@
+use serde::Serializer;
+use std::{fmt::Write, time::Duration};
+
+use crate::error::ParseError;
+
+/// Seconds per designator, in the order they must appear.
+const DATE_UNITS: &[(char, f64)] = &[('W', 604_800.0), ('D', 86_400.0)];
+const TIME_UNITS: &[(char, f64)] = &[('H', 3_600.0), ('M', 60.0), ('S', 1.0)];
+
+/// Parse an ISO 8601 duration such as `PT1H30M`, as used by schema.org times.
+///
+/// Years and months have no fixed length, so only weeks, days, hours, minutes
+/// and (fractional) seconds are accepted.
+pub fn parse_duration(content: &str) -> Result<Duration, ParseError> {
+    let invalid = || ParseError::InvalidContent(content.into());
+
+    let rest = content.trim().strip_prefix('P').ok_or_else(invalid)?;
+    let (date, time) = rest.split_once('T').unwrap_or((rest, ""));
+
+    // A bare `P`, or a `T` with no time components, is not a duration.
+    if time.is_empty() && (date.is_empty() || rest.ends_with('T')) {
+        return Err(invalid());
+    }
+
+    let seconds = parse_components(date, DATE_UNITS).ok_or_else(invalid)?
+        + parse_components(time, TIME_UNITS).ok_or_else(invalid)?;
+
+    Duration::try_from_secs_f64(seconds).map_err(|_| invalid())
+}
+
+/// Format a duration as ISO 8601, e.g. `PT1H30M`, with weeks written as days.
+pub fn format_duration(duration: &Duration) -> String {
+    let total = duration.as_secs();
+    let days = total / 86_400;
+    let hours = total % 86_400 / 3_600;
+    let minutes = total % 3_600 / 60;
+    let seconds = total % 60;
+    let nanos = duration.subsec_nanos();
+
+    let mut result = String::from("P");
+    if days > 0 {
+        let _ = write!(result, "{}D", days);
+    }
+
+    let mut time = String::new();
+    if hours > 0 {
+        let _ = write!(time, "{}H", hours);
+    }
+    if minutes > 0 {
+        let _ = write!(time, "{}M", minutes);
+    }
+    if nanos > 0 {
+        let fraction = format!("{:09}", nanos);
+        let _ = write!(time, "{}.{}S", seconds, fraction.trim_end_matches('0'));
+    } else if seconds > 0 || (days == 0 && time.is_empty()) {
+        let _ = write!(time, "{}S", seconds);
+    }
+
+    if !time.is_empty() {
+        result.push('T');
+        result.push_str(&time);
+    }
+    result
+}
+
+/// Serialize a duration as its ISO 8601 string.
+pub(super) fn serialize_duration<S: Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => serializer.serialize_str(&format_duration(duration)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Sum components such as `1H30M`, returning `None` if any are malformed.
+fn parse_components(mut part: &str, units: &[(char, f64)]) -> Option<f64> {
+    let mut units = units.iter();
+    let mut seconds = 0.0;
+
+    while !part.is_empty() {
+        let end = part.find(|c: char| c.is_ascii_alphabetic())?;
+        let designator = part[end..].chars().next()?;
+
+        // Designators must appear in order, each at most once.
+        let (_, scale) = units.find(|(unit, _)| *unit == designator)?;
+        let value: f64 = part[..end].parse().ok()?;
+
+        seconds += value * scale;
+        part = &part[end + 1..];
+    }
+    Some(seconds)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn duration_from_str() {
+        assert_eq!(
+            parse_duration("PT1H30M").unwrap(),
+            Duration::from_secs(5400)
+        );
+        assert_eq!(
+            parse_duration("P1DT2S").unwrap(),
+            Duration::from_secs(86402)
+        );
+        assert_eq!(
+            parse_duration("PT0.5S").unwrap(),
+            Duration::from_millis(500)
+        );
+        assert!(parse_duration("P1Y").is_err());
+        assert!(parse_duration("PT30M1H").is_err());
+        assert!(parse_duration("PT").is_err());
+        assert!(parse_duration("30 mins").is_err());
+    }
+
+    #[test]
+    fn duration_round_trip() {
+        for content in ["PT1H30M", "P1DT2S", "PT0.5S", "P2D", "PT0S"] {
+            let duration = parse_duration(content).unwrap();
+            assert_eq!(format_duration(&duration), content);
+        }
+        assert_eq!(format_duration(&parse_duration("P1W").unwrap()), "P7D");
+    }
+}
@@ -0,0 +1,8 @@
+mod duration;
+pub use duration::{format_duration, parse_duration};
+
+mod rating;
+pub use rating::Rating;
+
+mod recipe_object;
+pub use recipe_object::{Recipe, RecipeScraper};
@@ -0,0 +1,33 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// A schema.org `AggregateRating`.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct Rating {
+    pub value: Option<f64>,
+    pub count: Option<u64>,
+    pub best: Option<f64>,
+}
+
+/// Read a number which may be given as a JSON number or a string.
+fn number(value: Option<&Value>) -> Option<f64> {
+    match value? {
+        Value::Number(number) => number.as_f64(),
+        Value::String(string) => string.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+impl Rating {
+    pub(crate) fn from_json_ld(node: &Value) -> Self {
+        let count = number(node.get("ratingCount"))
+            .or_else(|| number(node.get("reviewCount")))
+            .map(|count| count as u64);
+
+        Self {
+            value: number(node.get("ratingValue")),
+            count,
+            best: number(node.get("bestRating")),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use scraper::{Html, Selector};
+use serde::Serialize;
+use serde_json::Value;
+use std::{error::Error, time::Duration};
+use url::Url;
+
+use super::{duration::serialize_duration, parse_duration, Rating};
+use crate::{
+    json_ld::{has_type, JsonLdScraper},
+    meta_data::normalize_url,
+    open_graph::OpenGraphObject,
+    scraper::{OpenGraphScraper, Scraper},
+};
+
+/// A recipe preview, merged from Open Graph, Pinterest and JSON-LD metadata.
+#[derive(Default, Debug, Serialize)]
+pub struct Recipe {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub images: Option<Vec<String>>,
+    pub ingredients: Option<Vec<String>>,
+    #[serde(serialize_with = "serialize_duration")]
+    pub prep_time: Option<Duration>,
+    #[serde(serialize_with = "serialize_duration")]
+    pub cook_time: Option<Duration>,
+    #[serde(serialize_with = "serialize_duration")]
+    pub total_time: Option<Duration>,
+    pub recipe_yield: Option<String>,
+    pub rating: Option<Rating>,
+    pub published_time: Option<String>,
+    pub see_also: Option<Vec<String>>,
+    /// False when the page opts out with `<meta name="pinterest-rich-pin" content="false">`.
+    pub rich_pin: bool,
+}
+
+/// Read a string, or the first string of an array.
+fn first_string(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(string) => Some(string.trim().into()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Array(values) => first_string(values.first()),
+        _ => None,
+    }
+}
+
+/// Read a string or array of strings.
+fn strings(value: Option<&Value>) -> Option<Vec<String>> {
+    match value? {
+        Value::String(string) => Some(vec![string.trim().into()]),
+        Value::Array(values) => Some(
+            values
+                .iter()
+                .filter_map(|value| value.as_str())
+                .map(|string| string.trim().into())
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// Read image urls, which may be strings or `ImageObject` nodes, resolved against the site url.
+fn images(site_url: &Url, value: Option<&Value>) -> Option<Vec<String>> {
+    let image_url = |value: &Value| {
+        let url = match value {
+            Value::String(url) => url.as_str(),
+            Value::Object(object) => object.get("url")?.as_str()?,
+            _ => return None,
+        };
+        Some(normalize_url(site_url, url).into_owned())
+    };
+
+    let urls = match value? {
+        Value::Array(values) => values.iter().filter_map(image_url).collect(),
+        value => image_url(value).into_iter().collect(),
+    };
+    Some(urls)
+}
+
+fn meta_content(document: &Html, selector: &str) -> Option<String> {
+    let selector = Selector::parse(selector).unwrap();
+    document
+        .select(&selector)
+        .next_back()
+        .and_then(|element| element.value().attr("content"))
+        .map(String::from)
+}
+
+impl Recipe {
+    fn update_from_open_graph(&mut self, open_graph: OpenGraphObject) {
+        self.name = self.name.take().or(open_graph.title);
+        self.description = self.description.take().or(open_graph.description);
+        self.see_also = open_graph.see_also;
+
+        if self.images.is_none() {
+            self.images = open_graph
+                .images
+                .map(|images| images.into_iter().map(|image| image.url).collect());
+        }
+    }
+
+    fn update_from_json_ld(&mut self, site_url: &Url, node: &Value) {
+        let duration = |key| {
+            node.get(key)
+                .and_then(Value::as_str)
+                .and_then(|content| parse_duration(content).ok())
+        };
+
+        self.name = first_string(node.get("name"));
+        self.description = first_string(node.get("description"));
+        self.images = images(site_url, node.get("image"));
+        self.ingredients =
+            strings(node.get("recipeIngredient")).or_else(|| strings(node.get("ingredients")));
+        self.prep_time = duration("prepTime");
+        self.cook_time = duration("cookTime");
+        self.total_time = duration("totalTime");
+        self.recipe_yield = first_string(node.get("recipeYield"));
+        self.rating = node.get("aggregateRating").map(Rating::from_json_ld);
+        self.published_time = first_string(node.get("datePublished"));
+    }
+}
+
+pub struct RecipeScraper {}
+
+impl RecipeScraper {
+    /// Scrape the document for a recipe.
+    ///
+    /// Returns `None` unless the page has `og:type` of `recipe` or a JSON-LD
+    /// `Recipe` node. JSON-LD values take precedence over Open Graph.
+    pub fn scrape(url: &str, html: &str) -> Result<Option<Recipe>, Box<dyn Error>> {
+        let open_graph = OpenGraphScraper::scrape(url, html)?;
+        let url = Url::parse(url)?;
+        let document = Html::parse_document(html);
+
+        let node = JsonLdScraper::scrape_document(&document)
+            .into_iter()
+            .find(|node| has_type(node, "Recipe"));
+        let is_recipe = open_graph
+            .media_type
+            .as_deref()
+            .is_some_and(|media_type| media_type.eq_ignore_ascii_case("recipe"));
+
+        if node.is_none() && !is_recipe {
+            return Ok(None);
+        }
+
+        let mut recipe = Recipe::default();
+
+        if let Some(node) = &node {
+            recipe.update_from_json_ld(&url, node);
+        }
+        recipe.update_from_open_graph(open_graph);
+
+        let published_time = meta_content(
+            &document,
+            r#"head > meta[property="article:published_time"]"#,
+        );
+        recipe.published_time = recipe.published_time.take().or(published_time);
+
+        let rich_pin = meta_content(&document, r#"head > meta[name="pinterest-rich-pin"]"#);
+        recipe.rich_pin = !rich_pin.is_some_and(|content| content.eq_ignore_ascii_case("false"));
+
+        Ok(Some(recipe))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scrape_merged() {
+        let recipe = RecipeScraper::scrape(
+            "http://x.com",
+            r#"<head>
+                <meta property="og:type" content="recipe" />
+                <meta property="og:title" content="Open Graph title" />
+                <meta property="og:description" content="X" />
+                <meta property="og:see_also" content="./other" />
+                <meta property="article:published_time" content="2023-01-01" />
+                <script type="application/ld+json">
+                    {
+                        "@type": "Recipe",
+                        "name": "Pancakes",
+                        "image": ["/a.jpg", {"@type": "ImageObject", "url": "http://y.com/b.jpg"}],
+                        "recipeIngredient": ["Flour", "Milk"],
+                        "cookTime": "PT20M",
+                        "recipeYield": ["4", "4 servings"],
+                        "aggregateRating": {"ratingValue": "4.5", "ratingCount": 10}
+                    }
+                </script>
+            </head>"#,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(recipe.name, Some("Pancakes".into()));
+        assert_eq!(recipe.description, Some("X".into()));
+        assert_eq!(
+            recipe.ingredients,
+            Some(vec!["Flour".into(), "Milk".into()])
+        );
+        assert_eq!(
+            recipe.images,
+            Some(vec!["http://x.com/a.jpg".into(), "http://y.com/b.jpg".into()])
+        );
+        assert_eq!(recipe.cook_time, Some(Duration::from_secs(1200)));
+        assert_eq!(serde_json::to_value(&recipe).unwrap()["cook_time"], "PT20M");
+        assert_eq!(recipe.recipe_yield, Some("4".into()));
+        assert_eq!(
+            recipe.rating,
+            Some(Rating {
+                value: Some(4.5),
+                count: Some(10),
+                best: None,
+            })
+        );
+        assert_eq!(recipe.published_time, Some("2023-01-01".into()));
+        assert_eq!(recipe.see_also, Some(vec!["http://x.com/other".into()]));
+        assert!(recipe.rich_pin);
+    }
+
+    #[test]
+    fn scrape_open_graph_only() {
+        let recipe = RecipeScraper::scrape(
+            "http://x.com",
+            r#"<head>
+                <meta property="og:type" content="recipe" />
+                <meta property="og:title" content="X" />
+                <meta name="pinterest-rich-pin" content="false" />
+            </head>"#,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(recipe.name, Some("X".into()));
+        assert!(recipe.ingredients.is_none());
+        assert!(!recipe.rich_pin);
+    }
+
+    #[test]
+    fn scrape_not_recipe() {
+        let recipe = RecipeScraper::scrape(
+            "http://x.com",
+            r#"<head>
+                <meta property="og:type" content="article" />
+            </head>"#,
+        )
+        .unwrap();
+
+        assert!(recipe.is_none());
+    }
+}
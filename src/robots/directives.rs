@@ -0,0 +1,119 @@
+use scraper::{Html, Selector};
+use serde::Serialize;
+use std::{collections::BTreeMap, sync::LazyLock};
+
+use super::{robots_directives::is_value_directive, RobotsDirectives};
+
+/// Crawler names which may be targeted by their own meta tag.
+const USER_AGENTS: &[&str] = &[
+    "googlebot",
+    "googlebot-news",
+    "googlebot-image",
+    "googlebot-video",
+    "google-extended",
+    "bingbot",
+    "msnbot",
+    "slurp",
+    "duckduckbot",
+    "yandex",
+    "baiduspider",
+];
+
+/// Robots directives for all crawlers and for specific user agents.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct Directives {
+    /// From `<meta name="robots">` and untargeted `X-Robots-Tag` meta.
+    pub robots: RobotsDirectives,
+    /// From `<meta name="googlebot">` and similar, keyed by lowercase user agent.
+    pub user_agents: BTreeMap<String, RobotsDirectives>,
+}
+
+impl Directives {
+    /// Get the effective directives for a crawler, combining general and specific tags.
+    pub fn for_user_agent(&self, user_agent: &str) -> RobotsDirectives {
+        match self.user_agents.get(&user_agent.to_ascii_lowercase()) {
+            Some(directives) => self.robots.merge(directives),
+            None => self.robots.clone(),
+        }
+    }
+
+    fn update_user_agent(&mut self, user_agent: &str, content: &str) {
+        self.user_agents
+            .entry(user_agent.to_ascii_lowercase())
+            .or_default()
+            .update_from(content);
+    }
+
+    /// Apply an `X-Robots-Tag` value, which may be prefixed with a user agent.
+    fn update_from_header(&mut self, content: &str) {
+        if let Some((user_agent, rest)) = content.split_once(':') {
+            if !user_agent.contains(',') && !is_value_directive(user_agent) {
+                self.update_user_agent(user_agent.trim(), rest);
+                return;
+            }
+        }
+        self.robots.update_from(content);
+    }
+
+    /// Get the CSS selector for robots meta elements.
+    fn selector() -> &'static Selector {
+        static SELECTOR: LazyLock<Selector> = LazyLock::new(|| {
+            Selector::parse("head > meta[name][content], head > meta[http-equiv][content]").unwrap()
+        });
+        &SELECTOR
+    }
+
+    /// Extract the directives from a parsed document head.
+    pub fn from_document(document: &Html) -> Self {
+        let mut directives = Self::default();
+
+        for element in document.select(Self::selector()) {
+            let value = element.value();
+            let content = value.attr("content").unwrap_or_default();
+
+            if let Some(name) = value.attr("name") {
+                if name.eq_ignore_ascii_case("robots") {
+                    directives.robots.update_from(content);
+                } else if USER_AGENTS
+                    .iter()
+                    .any(|user_agent| user_agent.eq_ignore_ascii_case(name))
+                {
+                    directives.update_user_agent(name, content);
+                }
+            } else if value
+                .attr("http-equiv")
+                .is_some_and(|name| name.eq_ignore_ascii_case("x-robots-tag"))
+            {
+                directives.update_from_header(content);
+            }
+        }
+
+        directives
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_document() {
+        let document = Html::parse_document(
+            r#"<head>
+                <meta name="robots" content="max-snippet:50" />
+                <meta name="Googlebot" content="noindex" />
+                <meta name="description" content="noindex" />
+                <meta http-equiv="X-Robots-Tag" content="bingbot: nofollow" />
+                <meta http-equiv="X-Robots-Tag" content="max-image-preview:none" />
+            </head>"#,
+        );
+        let directives = Directives::from_document(&document);
+
+        assert!(!directives.robots.noindex);
+        assert_eq!(directives.robots.max_snippet, Some(50));
+        assert!(directives.for_user_agent("googlebot").noindex);
+        assert_eq!(directives.for_user_agent("googlebot").max_snippet, Some(50));
+        assert!(directives.user_agents["bingbot"].nofollow);
+        assert!(directives.robots.max_image_preview.is_some());
+    }
+}
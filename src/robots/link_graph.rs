@@ -0,0 +1,109 @@
+use scraper::{Html, Selector};
+use serde::Serialize;
+use std::sync::LazyLock;
+use url::Url;
+
+use crate::meta_data::MetaData;
+
+/// Canonical and alternate versions of a page, from `<link>` elements.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct LinkGraph {
+    pub canonical: Option<String>,
+    pub amphtml: Option<String>,
+    pub alternates: Vec<Alternate>,
+}
+
+/// A `<link rel="alternate">`, such as a translation or feed.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct Alternate {
+    pub href: String,
+    pub hreflang: Option<String>,
+    pub media_type: Option<String>,
+    pub media: Option<String>,
+    pub title: Option<String>,
+}
+
+impl LinkGraph {
+    /// Get the CSS selector for link elements.
+    fn selector() -> &'static Selector {
+        static SELECTOR: LazyLock<Selector> =
+            LazyLock::new(|| Selector::parse("head > link[rel][href]").unwrap());
+        &SELECTOR
+    }
+
+    /// Extract the link graph from a parsed document head, resolving urls.
+    pub fn from_document(url: &Url, document: &Html) -> Self {
+        let mut links = Self::default();
+
+        for element in document.select(Self::selector()) {
+            let value = element.value();
+            let href = MetaData {
+                site_url: url,
+                tags: &[],
+                content: value.attr("href").unwrap_or_default().trim(),
+            }
+            .normalized_url();
+            let attr = |name| value.attr(name).map(String::from);
+
+            for rel in value.attr("rel").unwrap_or_default().split_whitespace() {
+                match rel.to_ascii_lowercase().as_str() {
+                    // The first canonical wins, as later ones are usually injected mistakes.
+                    "canonical" => {
+                        links.canonical.get_or_insert_with(|| href.clone());
+                    }
+                    "amphtml" => {
+                        links.amphtml.get_or_insert_with(|| href.clone());
+                    }
+                    "alternate" => links.alternates.push(Alternate {
+                        href: href.clone(),
+                        hreflang: attr("hreflang"),
+                        media_type: attr("type"),
+                        media: attr("media"),
+                        title: attr("title"),
+                    }),
+                    _ => {}
+                }
+            }
+        }
+
+        links
+    }
+
+    /// Get the alternate url for a language, e.g. `en-GB` or `x-default`.
+    pub fn hreflang(&self, language: &str) -> Option<&str> {
+        self.alternates
+            .iter()
+            .find(|alternate| {
+                alternate
+                    .hreflang
+                    .as_deref()
+                    .is_some_and(|hreflang| hreflang.eq_ignore_ascii_case(language))
+            })
+            .map(|alternate| alternate.href.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_document() {
+        let url = Url::parse("http://x.com/a").unwrap();
+        let document = Html::parse_document(
+            r#"<head>
+                <link rel="canonical" href="/canonical" />
+                <link rel="canonical" href="/other" />
+                <link rel="amphtml" href="http://amp.x.com/a" />
+                <link rel="alternate" hreflang="fr" href="/fr/a" />
+                <link rel="alternate" type="application/rss+xml" href="/feed" />
+            </head>"#,
+        );
+        let links = LinkGraph::from_document(&url, &document);
+
+        assert_eq!(links.canonical, Some("http://x.com/canonical".into()));
+        assert_eq!(links.amphtml, Some("http://amp.x.com/a".into()));
+        assert_eq!(links.hreflang("FR"), Some("http://x.com/fr/a"));
+        assert_eq!(links.alternates.len(), 2);
+    }
+}
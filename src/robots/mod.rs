@@ -0,0 +1,8 @@
+mod robots_directives;
+pub use robots_directives::{ImagePreview, RobotsDirectives};
+
+mod directives;
+pub use directives::Directives;
+
+mod link_graph;
+pub use link_graph::{Alternate, LinkGraph};
@@ -0,0 +1,150 @@
+use serde::Serialize;
+use std::str::FromStr;
+use strum::EnumString;
+
+/// Indexing directives from a robots meta tag or `X-Robots-Tag`.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct RobotsDirectives {
+    pub noindex: bool,
+    pub nofollow: bool,
+    pub noarchive: bool,
+    pub nosnippet: bool,
+    pub noimageindex: bool,
+    pub notranslate: bool,
+    pub max_image_preview: Option<ImagePreview>,
+    /// The maximum snippet length in characters, where `-1` is unlimited.
+    pub max_snippet: Option<i64>,
+    /// The maximum video preview length in seconds, where `-1` is unlimited.
+    pub max_video_preview: Option<i64>,
+    pub unavailable_after: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumString, Serialize)]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+#[serde(rename_all = "snake_case")]
+pub enum ImagePreview {
+    None,
+    Standard,
+    Large,
+}
+
+/// Directive names which take a value after a colon, e.g. `max-snippet:50`.
+const VALUE_DIRECTIVES: &[&str] = &[
+    "max-image-preview",
+    "max-snippet",
+    "max-video-preview",
+    "unavailable_after",
+];
+
+/// Whether `name` is a directive, rather than a user agent in `googlebot: noindex`.
+pub(crate) fn is_value_directive(name: &str) -> bool {
+    VALUE_DIRECTIVES
+        .iter()
+        .any(|directive| directive.eq_ignore_ascii_case(name.trim()))
+}
+
+/// Take the more restrictive of two limits, where `-1` is unlimited.
+fn min_limit(a: Option<i64>, b: Option<i64>) -> Option<i64> {
+    match (a, b) {
+        (Some(-1), other) | (other, Some(-1)) => other.or(Some(-1)),
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+impl RobotsDirectives {
+    /// Apply a comma separated list of directives, e.g. `noindex, max-snippet:50`.
+    ///
+    /// Unknown directives and invalid values are ignored, as by search engines.
+    pub fn update_from(&mut self, content: &str) {
+        for directive in content.split(',') {
+            let directive = directive.trim();
+            let (name, value) = directive
+                .split_once(':')
+                .map(|(name, value)| (name.trim(), value.trim()))
+                .unwrap_or((directive, ""));
+
+            match name.to_ascii_lowercase().as_str() {
+                "noindex" => self.noindex = true,
+                "nofollow" => self.nofollow = true,
+                "none" => {
+                    self.noindex = true;
+                    self.nofollow = true;
+                }
+                "noarchive" | "nocache" => self.noarchive = true,
+                "nosnippet" => self.nosnippet = true,
+                "noimageindex" => self.noimageindex = true,
+                "notranslate" => self.notranslate = true,
+                "max-image-preview" => {
+                    if let Ok(preview) = ImagePreview::from_str(value) {
+                        self.max_image_preview = Some(preview);
+                    }
+                }
+                "max-snippet" => {
+                    if let Ok(limit) = value.parse() {
+                        self.max_snippet = Some(limit);
+                    }
+                }
+                "max-video-preview" => {
+                    if let Ok(limit) = value.parse() {
+                        self.max_video_preview = Some(limit);
+                    }
+                }
+                "unavailable_after" => {
+                    self.unavailable_after = Some(value.into());
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Combine with directives from another tag, keeping the most restrictive.
+    pub fn merge(&self, other: &Self) -> Self {
+        Self {
+            noindex: self.noindex || other.noindex,
+            nofollow: self.nofollow || other.nofollow,
+            noarchive: self.noarchive || other.noarchive,
+            nosnippet: self.nosnippet || other.nosnippet,
+            noimageindex: self.noimageindex || other.noimageindex,
+            notranslate: self.notranslate || other.notranslate,
+            max_image_preview: match (self.max_image_preview, other.max_image_preview) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            },
+            max_snippet: min_limit(self.max_snippet, other.max_snippet),
+            max_video_preview: min_limit(self.max_video_preview, other.max_video_preview),
+            unavailable_after: self
+                .unavailable_after
+                .clone()
+                .or_else(|| other.unavailable_after.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn update() {
+        let mut directives = RobotsDirectives::default();
+        directives.update_from("None, max-image-preview:large, max-snippet: 50, unknown");
+
+        assert!(directives.noindex && directives.nofollow);
+        assert_eq!(directives.max_image_preview, Some(ImagePreview::Large));
+        assert_eq!(directives.max_snippet, Some(50));
+    }
+
+    #[test]
+    fn merge() {
+        let mut a = RobotsDirectives::default();
+        a.update_from("max-snippet:-1, max-image-preview:large");
+        let mut b = RobotsDirectives::default();
+        b.update_from("noarchive, max-snippet:20, max-image-preview:standard");
+
+        let merged = a.merge(&b);
+        assert!(merged.noarchive);
+        assert_eq!(merged.max_snippet, Some(20));
+        assert_eq!(merged.max_image_preview, Some(ImagePreview::Standard));
+    }
+}
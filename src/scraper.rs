@@ -1,12 +1,26 @@
 use scraper::{ElementRef, Html, Selector};
-use std::error::Error;
+use std::{error::Error, sync::LazyLock};
 use url::Url;
 
 use crate::{
-    graph_object::GraphObject, meta_data::MetaData, open_graph::OpenGraphObject,
+    app_links::AppLinksObject, error::{Diagnostic, ParseError}, facebook::FacebookObject,
+    graph_object::GraphObject,
+    meta_data::{split_tags, MetaData, MAX_TAGS},
+    open_graph::OpenGraphObject, product::ProductObject,
     twitter::TwitterGraphObject,
 };
 
+/// Implement `Scraper::selector` with a static holding the scraper's `build_selector`,
+/// so each scraper compiles its selector once.
+macro_rules! cached_selector {
+    ($scraper:ty) => {
+        fn selector() -> &'static Selector {
+            static SELECTOR: LazyLock<Selector> = LazyLock::new(<$scraper>::build_selector);
+            &SELECTOR
+        }
+    };
+}
+
 pub trait Scraper {
     type RootGraphObject: GraphObject + Default;
 
@@ -21,8 +35,8 @@ pub trait Scraper {
         &html[0..end]
     }
 
-    /// Get the CSS selector for meta elements.
-    fn selector() -> Selector {
+    /// Build the CSS selector for meta elements.
+    fn build_selector() -> Selector {
         let selector = format!(
             r#"head > meta[{}^="{}"]"#,
             Self::attribute(),
@@ -31,6 +45,9 @@ pub trait Scraper {
         Selector::parse(&selector).unwrap()
     }
 
+    /// Get the selector for meta elements, compiled once with `cached_selector!`.
+    fn selector() -> &'static Selector;
+
     /// Get the property attribute from an element.
     fn get_property<'a>(element: &ElementRef<'a>) -> Option<&'a str> {
         element
@@ -40,7 +57,7 @@ pub trait Scraper {
                 if !property.starts_with(Self::RootGraphObject::prefix()) {
                     return None;
                 }
-                return Some(property);
+                Some(property)
             })
     }
 
@@ -57,29 +74,76 @@ pub trait Scraper {
         let head = Self::extract_head(html);
         let document = Html::parse_document(head);
 
-        let selector = Self::selector();
-        let elements = document.select(&selector);
+        Ok(Self::scrape_document(&url, &document))
+    }
+
+    /// Scrape the document, also returning the properties which could not be applied.
+    fn scrape_with_diagnostics(
+        url: &str,
+        html: &str,
+    ) -> Result<(Self::RootGraphObject, Vec<Diagnostic>), Box<dyn Error>> {
+        let url = Url::parse(url)?;
+
+        let head = Self::extract_head(html);
+        let document = Html::parse_document(head);
+
+        Ok(Self::scrape_document_with_diagnostics(
+            &url,
+            &document,
+            Self::selector(),
+        ))
+    }
+
+    /// Scrape an already parsed document for properties.
+    fn scrape_document(url: &Url, document: &Html) -> Self::RootGraphObject {
+        Self::scrape_document_with(url, document, Self::selector())
+    }
+
+    /// Scrape an already parsed document with a given compiled selector.
+    fn scrape_document_with(url: &Url, document: &Html, selector: &Selector) -> Self::RootGraphObject {
+        Self::scrape_document_with_diagnostics(url, document, selector).0
+    }
+
+    /// Scrape an already parsed document, collecting the properties which could not be applied.
+    fn scrape_document_with_diagnostics(
+        url: &Url,
+        document: &Html,
+        selector: &Selector,
+    ) -> (Self::RootGraphObject, Vec<Diagnostic>) {
+        let elements = document.select(selector);
 
         let mut result = Self::RootGraphObject::default();
+        let mut diagnostics = vec![];
 
         for element in elements {
             let property = Self::get_property(&element);
             let content = Self::get_content(&element);
 
             if let (Some(property), Some(content)) = (property, content) {
-                let property_tags: Vec<&str> = property.split(":").collect();
-
-                let meta_data = MetaData {
-                    site_url: &url,
-                    tags: &property_tags,
-                    content,
+                let mut buffer = [""; MAX_TAGS];
+                let update = match split_tags(property, &mut buffer) {
+                    Some(property_tags) => result.update_from(
+                        MetaData {
+                            site_url: url,
+                            tags: property_tags,
+                            content,
+                        }
+                        .next(),
+                    ),
+                    None => Err(ParseError::InvalidPropertyTag),
                 };
 
-                let _ = result.update_from(meta_data.next());
+                if let Err(error) = update {
+                    diagnostics.push(Diagnostic {
+                        property: property.into(),
+                        content: content.into(),
+                        error,
+                    });
+                }
             }
         }
 
-        return Ok(result);
+        (result, diagnostics)
     }
 }
 
@@ -87,6 +151,8 @@ pub struct OpenGraphScraper {}
 
 impl Scraper for OpenGraphScraper {
     type RootGraphObject = OpenGraphObject;
+
+    cached_selector!(OpenGraphScraper);
 }
 
 pub struct TwitterScraper {}
@@ -94,11 +160,61 @@ pub struct TwitterScraper {}
 impl Scraper for TwitterScraper {
     type RootGraphObject = TwitterGraphObject;
 
+    cached_selector!(TwitterScraper);
+
     fn attribute() -> &'static str {
         "name"
     }
 }
 
+pub struct AppLinksScraper {}
+
+impl Scraper for AppLinksScraper {
+    type RootGraphObject = AppLinksObject;
+
+    cached_selector!(AppLinksScraper);
+}
+
+pub struct ProductScraper {}
+
+impl Scraper for ProductScraper {
+    type RootGraphObject = ProductObject;
+
+    cached_selector!(ProductScraper);
+}
+
+pub struct FacebookScraper {}
+
+impl FacebookScraper {
+    /// The Open Graph article property linking to the publisher's Facebook page.
+    const PUBLISHER_PROPERTY: &'static str = "article:publisher";
+}
+
+impl Scraper for FacebookScraper {
+    type RootGraphObject = FacebookObject;
+
+    cached_selector!(FacebookScraper);
+
+    fn build_selector() -> Selector {
+        let selector = format!(
+            r#"head > meta[property^="{}"], head > meta[property="{}"]"#,
+            Self::RootGraphObject::prefix(),
+            Self::PUBLISHER_PROPERTY
+        );
+        Selector::parse(&selector).unwrap()
+    }
+
+    fn get_property<'a>(element: &ElementRef<'a>) -> Option<&'a str> {
+        element
+            .value()
+            .attr(Self::attribute())
+            .filter(|property| {
+                property.starts_with(Self::RootGraphObject::prefix())
+                    || *property == Self::PUBLISHER_PROPERTY
+            })
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -114,6 +230,7 @@ mod tests {
         fs::read_to_string(document_abs_path).unwrap()
     }
 
+    #[allow(dead_code)]
     fn scrape_verge() -> TestGraphObject {
         TestScraper::scrape(
             "https://www.theverge.com/",
@@ -249,3 +366,557 @@ mod tests {
         )
     }
 }
+
+#[cfg(test)]
+mod twitter_tests {
+
+    use super::*;
+    use crate::twitter::{
+        property::{Creator, Image, Player},
+        TwitterGraphObject,
+    };
+
+    type TestScraper = TwitterScraper;
+    type TestGraphObject = TwitterGraphObject;
+
+    // Helper to scrape HTML string.
+    fn scrape(html: &str) -> TestGraphObject {
+        TestScraper::scrape("http://x.com", html).unwrap()
+    }
+
+    #[test]
+    fn scrape_title_single() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:title" content="X" />
+                    </head>"#,
+        );
+        assert_eq!(result.title, Some("X".into()));
+    }
+
+    #[test]
+    fn scrape_title_multiple() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:title" content="X" />
+                        <meta name="twitter:title" content="Y" />
+                    </head>"#,
+        );
+        assert_eq!(result.title, Some("Y".into()), "Should equal last value");
+    }
+
+    #[test]
+    fn no_scrape_property_attribute() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="twitter:title" content="X" />
+                    </head>"#,
+        );
+        assert_eq!(result.title, None);
+    }
+
+    #[test]
+    fn no_scrape_outside_head() {
+        let result = scrape(
+            r#"<head></head>
+            <body>
+                <meta name="twitter:title" content="X" />
+            </body>
+                    "#,
+        );
+        assert_eq!(result.title, None);
+    }
+
+    #[test]
+    fn scrape_image() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:image" content="http://x.com/image.jpg" />
+                        <meta name="twitter:image:alt" content="alt" />
+                        <meta name="twitter:image:width" content="10" />
+                        <meta name="twitter:image:height" content="10" />
+                    </head>"#,
+        );
+        assert_eq!(
+            result.image,
+            Some(Image {
+                url: "http://x.com/image.jpg".into(),
+                width: Some(10),
+                height: Some(10),
+                alt: Some("alt".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn scrape_image_src() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:image:src" content="http://x.com/image.jpg" />
+                    </head>"#,
+        );
+        assert_eq!(result.image.unwrap().url, "http://x.com/image.jpg");
+    }
+
+    #[test]
+    fn scrape_image_relative_url() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:image" content="./image.jpg" />
+                    </head>"#,
+        );
+        assert_eq!(
+            result.image,
+            Some(Image {
+                url: "http://x.com/image.jpg".into(),
+                ..Image::default()
+            })
+        )
+    }
+
+    #[test]
+    fn scrape_creator() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:creator" content="@x" />
+                        <meta name="twitter:creator:id" content="123" />
+                    </head>"#,
+        );
+        assert_eq!(
+            result.creator,
+            Some(Creator {
+                username: "@x".into(),
+                id: Some("123".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn scrape_site() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:site" content="@x" />
+                        <meta name="twitter:site:id" content="123" />
+                    </head>"#,
+        );
+        assert_eq!(result.site, Some("@x".into()));
+        assert_eq!(result.site_id, Some("123".into()));
+    }
+
+    #[test]
+    fn scrape_player() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:player" content="./player" />
+                        <meta name="twitter:player:width" content="10" />
+                        <meta name="twitter:player:height" content="10" />
+                        <meta name="twitter:player:stream" content="./stream.mp4" />
+                        <meta name="twitter:player:stream:content_type" content="video/mp4" />
+                    </head>"#,
+        );
+        assert_eq!(
+            result.player,
+            Some(Player {
+                url: "http://x.com/player".into(),
+                width: Some(10),
+                height: Some(10),
+                stream: Some("http://x.com/stream.mp4".into()),
+                stream_content_type: Some("video/mp4".into()),
+            })
+        );
+    }
+
+    #[test]
+    fn scrape_player_invalid_width() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:player" content="./player" />
+                        <meta name="twitter:player:width" content="X" />
+                    </head>"#,
+        );
+        assert_eq!(result.player.unwrap().width, None);
+    }
+
+    #[test]
+    fn scrape_app() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:app:country" content="GB" />
+                        <meta name="twitter:app:name:ipad" content="X" />
+                        <meta name="twitter:app:id:ipad" content="123" />
+                        <meta name="twitter:app:url:ipad" content="x://open" />
+                    </head>"#,
+        );
+        let app = result.app.unwrap();
+        assert_eq!(app.country, Some("GB".into()));
+        let ipad = app.ipad.unwrap();
+        assert_eq!(ipad.name, Some("X".into()));
+        assert_eq!(ipad.id, Some("123".into()));
+        assert_eq!(ipad.url, Some("x://open".into()));
+    }
+
+    #[test]
+    fn scrape_gallery() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:image0" content="./0.jpg" />
+                        <meta name="twitter:image1:src" content="./1.jpg" />
+                    </head>"#,
+        );
+        let gallery = result.gallery.unwrap();
+        assert_eq!(gallery[0].as_ref().unwrap().url, "http://x.com/0.jpg");
+        assert_eq!(gallery[1].as_ref().unwrap().url, "http://x.com/1.jpg");
+        assert!(gallery[2].is_none());
+    }
+
+    #[test]
+    fn scrape_labels() {
+        let result = scrape(
+            r#"<head>
+                        <meta name="twitter:label1" content="Written by" />
+                        <meta name="twitter:data1" content="X" />
+                        <meta name="twitter:label2" content="Reading time" />
+                        <meta name="twitter:data2" content="5 min" />
+                    </head>"#,
+        );
+        let labels = result.labels.unwrap();
+        assert_eq!(labels[0].data, Some("X".into()));
+        assert_eq!(labels[1].label, Some("Reading time".into()));
+    }
+
+    #[test]
+    fn scrape_with_diagnostics() {
+        let (result, diagnostics) = TestScraper::scrape_with_diagnostics(
+            "http://x.com",
+            r#"<head>
+                        <meta name="twitter:title" content="X" />
+                        <meta name="twitter:unknown" content="Y" />
+                        <meta name="twitter:label3" content="Z" />
+                    </head>"#,
+        )
+        .unwrap();
+        assert_eq!(result.title, Some("X".into()));
+        let properties: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.property.as_str())
+            .collect();
+        assert_eq!(properties, ["twitter:unknown", "twitter:label3"]);
+        assert!(matches!(diagnostics[0].error, ParseError::InvalidPropertyTag));
+    }
+}
+
+#[cfg(test)]
+mod app_links_tests {
+
+    use super::*;
+    use crate::app_links::property::{AndroidApp, IosApp};
+
+    type TestScraper = AppLinksScraper;
+    type TestGraphObject = AppLinksObject;
+
+    // Helper to scrape HTML string.
+    fn scrape(html: &str) -> TestGraphObject {
+        TestScraper::scrape("http://x.com", html).unwrap()
+    }
+
+    #[test]
+    fn scrape_ios() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="al:ios:url" content="x://docs" />
+                        <meta property="al:ios:app_store_id" content="123" />
+                        <meta property="al:ios:app_name" content="X" />
+                    </head>"#,
+        );
+        assert_eq!(
+            result.ios,
+            Some(vec![IosApp {
+                url: "x://docs".into(),
+                app_store_id: Some("123".into()),
+                app_name: Some("X".into()),
+            }])
+        );
+    }
+
+    #[test]
+    fn scrape_android_package_only() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="al:android:package" content="com.x" />
+                        <meta property="al:android:app_name" content="X" />
+                    </head>"#,
+        );
+        assert_eq!(
+            result.android,
+            Some(vec![AndroidApp {
+                package: "com.x".into(),
+                app_name: Some("X".into()),
+                ..AndroidApp::default()
+            }])
+        );
+    }
+
+    #[test]
+    fn scrape_android_multiple() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="al:android:url" content="x://v2" />
+                        <meta property="al:android:package" content="com.x.v2" />
+                        <meta property="al:android:url" content="x://v1" />
+                        <meta property="al:android:package" content="com.x.v1" />
+                        <meta property="al:android:class" content="com.x.Main" />
+                        <meta property="al:android:package" content="com.x.lite" />
+                        <meta property="al:android:package" content="com.x.beta" />
+                    </head>"#,
+        );
+        assert_eq!(
+            result.android,
+            Some(vec![
+                AndroidApp {
+                    package: "com.x.v2".into(),
+                    url: Some("x://v2".into()),
+                    ..AndroidApp::default()
+                },
+                AndroidApp {
+                    package: "com.x.v1".into(),
+                    url: Some("x://v1".into()),
+                    class: Some("com.x.Main".into()),
+                    ..AndroidApp::default()
+                },
+                AndroidApp {
+                    package: "com.x.lite".into(),
+                    ..AndroidApp::default()
+                },
+                AndroidApp {
+                    package: "com.x.beta".into(),
+                    ..AndroidApp::default()
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn scrape_platform_variants() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="al:iphone:url" content="x://iphone" />
+                        <meta property="al:ipad:url" content="x://ipad" />
+                        <meta property="al:windows_phone:url" content="x://phone" />
+                        <meta property="al:windows_phone:app_id" content="123" />
+                    </head>"#,
+        );
+        assert_eq!(result.iphone.unwrap()[0].url, "x://iphone");
+        assert_eq!(result.ipad.unwrap()[0].url, "x://ipad");
+        assert_eq!(
+            result.windows_phone.unwrap()[0].app_id,
+            Some("123".into())
+        );
+        assert!(result.ios.is_none());
+    }
+
+    #[test]
+    fn scrape_web() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="al:web:url" content="./docs" />
+                        <meta property="al:web:should_fallback" content="false" />
+                    </head>"#,
+        );
+        let web = result.web.unwrap();
+        assert_eq!(web.url, Some("http://x.com/docs".into()));
+        assert_eq!(web.should_fallback, Some(false));
+    }
+
+    #[test]
+    fn scrape_no_url() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="al:ios:app_name" content="X" />
+                    </head>"#,
+        );
+        assert!(result.ios.is_none());
+    }
+
+    #[test]
+    fn scrape_invalid_tag() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="al:ios" content="x://docs" />
+                        <meta property="al:windows" content="x://docs" />
+                    </head>"#,
+        );
+        assert!(result.ios.is_none());
+        assert!(result.windows.is_none());
+    }
+}
+
+#[cfg(test)]
+mod facebook_tests {
+
+    use super::*;
+
+    type TestScraper = FacebookScraper;
+    type TestGraphObject = FacebookObject;
+
+    // Helper to scrape HTML string.
+    fn scrape(html: &str) -> TestGraphObject {
+        TestScraper::scrape("http://x.com", html).unwrap()
+    }
+
+    #[test]
+    fn scrape_properties() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="fb:app_id" content="1" />
+                        <meta property="fb:pages" content="2,3" />
+                        <meta property="fb:admins" content="4" />
+                        <meta property="fb:profile_id" content="5" />
+                    </head>"#,
+        );
+        assert_eq!(result.app_id, Some("1".into()));
+        assert_eq!(result.pages, Some(vec!["2".into(), "3".into()]));
+        assert_eq!(result.admins, Some(vec!["4".into()]));
+        assert_eq!(result.profile_id, Some("5".into()));
+    }
+
+    #[test]
+    fn scrape_publisher() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="article:publisher" content="https://www.facebook.com/x" />
+                        <meta property="article:author" content="https://www.facebook.com/y" />
+                    </head>"#,
+        );
+        assert_eq!(result.publisher, Some("https://www.facebook.com/x".into()));
+    }
+}
+
+#[cfg(test)]
+mod product_tests {
+
+    use super::*;
+    use crate::product::property::{Amount, Availability, Condition};
+
+    type TestScraper = ProductScraper;
+    type TestGraphObject = ProductObject;
+
+    // Helper to scrape HTML string.
+    fn scrape(html: &str) -> TestGraphObject {
+        TestScraper::scrape("http://x.com", html).unwrap()
+    }
+
+    #[test]
+    fn scrape_properties() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="product:availability" content="in stock" />
+                        <meta property="product:condition" content="new" />
+                        <meta property="product:retailer_item_id" content="1" />
+                        <meta property="product:brand" content="X" />
+                    </head>"#,
+        );
+        assert_eq!(result.availability, Some(Availability::InStock));
+        assert_eq!(result.condition, Some(Condition::New));
+        assert_eq!(result.retailer_item_id, Some("1".into()));
+        assert_eq!(result.brand, Some("X".into()));
+    }
+
+    #[test]
+    fn scrape_prices_multiple() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="product:price:amount" content="10.00" />
+                        <meta property="product:price:currency" content="USD" />
+                        <meta property="product:price:amount" content="8.00" />
+                        <meta property="product:price:currency" content="GBP" />
+                    </head>"#,
+        );
+        let prices = result.prices.unwrap();
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices[1].amount, Some(Amount::new(800, 2)));
+        assert_eq!(prices[1].currency.as_ref().unwrap().code(), "GBP");
+    }
+
+    #[test]
+    fn scrape_sale_price() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="product:original_price:amount" content="20" />
+                        <meta property="product:sale_price:amount" content="15.99" />
+                        <meta property="product:sale_price:currency" content="EUR" />
+                    </head>"#,
+        );
+        assert_eq!(result.original_price.unwrap().amount, Some(Amount::new(20, 0)));
+        assert_eq!(result.sale_price.unwrap().amount.unwrap().to_string(), "15.99");
+    }
+
+    #[test]
+    fn scrape_invalid_amount() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="product:price:amount" content="10" />
+                        <meta property="product:price:currency" content="USD" />
+                        <meta property="product:price:amount" content="ten" />
+                        <meta property="product:price:currency" content="GBP" />
+                    </head>"#,
+        );
+        let prices = result.prices.unwrap();
+        assert_eq!(prices.len(), 2);
+        assert_eq!(prices[0].currency.as_ref().unwrap().code(), "USD");
+        assert_eq!(prices[1].amount, None);
+        assert_eq!(prices[1].currency.as_ref().unwrap().code(), "GBP");
+    }
+
+    #[test]
+    fn scrape_sale_price_currency_only() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="product:sale_price:currency" content="EUR" />
+                    </head>"#,
+        );
+        let sale_price = result.sale_price.unwrap();
+        assert_eq!(sale_price.amount, None);
+        assert_eq!(sale_price.currency.unwrap().code(), "EUR");
+    }
+
+    #[test]
+    fn scrape_invalid_currency() {
+        let result = scrape(
+            r#"<head>
+                        <meta property="product:price:amount" content="10" />
+                        <meta property="product:price:currency" content="XYZ" />
+                    </head>"#,
+        );
+        assert!(result.prices.unwrap()[0].currency.is_none());
+    }
+
+    #[test]
+    fn scrape_og_price() {
+        let result = OpenGraphScraper::scrape(
+            "http://x.com",
+            r#"<head>
+                        <meta property="og:price:amount" content="1.50" />
+                        <meta property="og:price:currency" content="USD" />
+                    </head>"#,
+        )
+        .unwrap();
+        assert_eq!(result.prices.unwrap()[0].amount, Some(Amount::new(150, 2)));
+    }
+    #[test]
+    fn scrape_og_price_invalid_amount() {
+        let result = OpenGraphScraper::scrape(
+            "http://x.com",
+            r#"<head>
+                        <meta property="og:price:amount" content="1.50" />
+                        <meta property="og:price:currency" content="USD" />
+                        <meta property="og:price:amount" content="free" />
+                        <meta property="og:price:currency" content="EUR" />
+                    </head>"#,
+        )
+        .unwrap();
+        let prices = result.prices.unwrap();
+        assert_eq!(prices[0].currency.as_ref().unwrap().code(), "USD");
+        assert_eq!(prices[1].amount, None);
+        assert_eq!(prices[1].currency.as_ref().unwrap().code(), "EUR");
+    }
+}
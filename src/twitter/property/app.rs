@@ -0,0 +1,110 @@
+use serde::Serialize;
+
+use crate::{error::ParseError, graph_object::GraphObject, meta_data::MetaData};
+
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct App {
+    pub country: Option<String>,
+    pub iphone: Option<AppPlatform>,
+    pub ipad: Option<AppPlatform>,
+    pub googleplay: Option<AppPlatform>,
+}
+
+/// The app entry for a single store platform.
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct AppPlatform {
+    pub name: Option<String>,
+    pub id: Option<String>,
+    pub url: Option<String>,
+}
+
+impl App {
+    fn platform_mut(&mut self, platform: &str) -> Option<&mut AppPlatform> {
+        let platform = match platform {
+            "iphone" => &mut self.iphone,
+            "ipad" => &mut self.ipad,
+            "googleplay" => &mut self.googleplay,
+            _ => return None,
+        };
+        Some(platform.get_or_insert(AppPlatform::default()))
+    }
+}
+
+impl GraphObject for App {
+    fn prefix() -> &'static str {
+        "app"
+    }
+
+    fn update_from(&mut self, data: MetaData) -> Result<(), ParseError> {
+        match data.tags {
+            ["country"] => {
+                self.country = Some(data.content.into());
+            }
+            [field @ ("name" | "id" | "url"), platform] => {
+                let platform = self
+                    .platform_mut(platform)
+                    .ok_or(ParseError::InvalidPropertyTag)?;
+
+                // App urls are custom scheme deep links, so are kept as supplied.
+                let value = Some(data.content.into());
+                match *field {
+                    "name" => platform.name = value,
+                    "id" => platform.id = value,
+                    _ => platform.url = value,
+                }
+            }
+            _ => return Err(ParseError::InvalidPropertyTag),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use url::Url;
+
+    use super::*;
+
+    #[test]
+    fn update_platforms() {
+        let mut app = App::default();
+        let site_url = Url::parse("http://x.com").unwrap();
+
+        for (tags, content) in [
+            (["name", "iphone"], "X"),
+            (["id", "iphone"], "123"),
+            (["url", "googleplay"], "x://open"),
+        ] {
+            app.update_from(MetaData {
+                site_url: &site_url,
+                tags: &tags,
+                content,
+            })
+            .unwrap();
+        }
+
+        assert_eq!(
+            app.iphone,
+            Some(AppPlatform {
+                name: Some("X".into()),
+                id: Some("123".into()),
+                url: None,
+            })
+        );
+        assert_eq!(app.googleplay.unwrap().url, Some("x://open".into()));
+        assert!(app.ipad.is_none());
+    }
+
+    #[test]
+    fn update_unknown_platform() {
+        let mut app = App::default();
+        let site_url = Url::parse("http://x.com").unwrap();
+
+        let result = app.update_from(MetaData {
+            site_url: &site_url,
+            tags: &["name", "windows"],
+            content: "X",
+        });
+        assert!(result.is_err());
+    }
+}
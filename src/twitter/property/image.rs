@@ -5,6 +5,8 @@ use crate::{error::ParseError, meta_data::MetaData, graph_object::GraphObject};
 #[derive(Default, Debug, PartialEq, Serialize)]
 pub struct Image {
     pub url: String,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
     pub alt: Option<String>,
 }
 
@@ -18,6 +20,20 @@ impl GraphObject for Image {
             [] | ["src"] => {
                 self.url = data.normalized_url();
             }
+            ["width"] => {
+                self.width = Some(
+                    data.content
+                        .parse()
+                        .map_err(|_| ParseError::InvalidContent(data.content.into()))?,
+                );
+            }
+            ["height"] => {
+                self.height = Some(
+                    data.content
+                        .parse()
+                        .map_err(|_| ParseError::InvalidContent(data.content.into()))?,
+                );
+            }
             ["alt"] => {
                 self.alt = Some(data.content.into());
             }
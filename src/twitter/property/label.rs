@@ -0,0 +1,8 @@
+use serde::Serialize;
+
+/// A `twitter:labelN` / `twitter:dataN` pair, e.g. "Reading time" / "5 min".
+#[derive(Default, Debug, PartialEq, Serialize)]
+pub struct Label {
+    pub label: Option<String>,
+    pub data: Option<String>,
+}
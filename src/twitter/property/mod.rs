@@ -5,4 +5,10 @@ mod image;
 pub use image::Image;
 
 mod player;
-pub use player::Player;
\ No newline at end of file
+pub use player::Player;
+
+mod app;
+pub use app::{App, AppPlatform};
+
+mod label;
+pub use label::Label;
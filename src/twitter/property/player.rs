@@ -8,6 +8,7 @@ pub struct Player {
     pub width: Option<u32>,
     pub height: Option<u32>,
     pub stream: Option<String>,
+    pub stream_content_type: Option<String>,
 }
 
 impl GraphObject for Player {
@@ -37,6 +38,9 @@ impl GraphObject for Player {
             ["stream"] => {
                 self.stream = Some(data.normalized_url());
             }
+            ["stream", "content_type"] => {
+                self.stream_content_type = Some(data.content.into());
+            }
             _ => return Err(ParseError::InvalidPropertyTag),
         }
         Ok(())
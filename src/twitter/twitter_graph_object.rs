@@ -2,17 +2,70 @@ use serde::Serialize;
 
 use crate::{error::ParseError, graph_object::GraphObject, meta_data::MetaData};
 
-use super::property::{Image, Creator, Player};
+use super::property::{App, Creator, Image, Label, Player};
+
+/// Number of images in the legacy gallery card, `twitter:image0` to `twitter:image3`.
+const GALLERY_SIZE: usize = 4;
+
+/// Number of label/data pairs, `twitter:label1` to `twitter:label2`.
+const LABEL_COUNT: usize = 2;
 
 #[derive(Default, Debug, Serialize)]
 pub struct TwitterGraphObject {
     pub card: Option<String>,
     pub site: Option<String>,
+    /// The numeric id from `twitter:site:id`, which is stable across username changes.
+    pub site_id: Option<String>,
     pub creator: Option<Creator>,
     pub description: Option<String>,
     pub title: Option<String>,
     pub image: Option<Image>,
+    pub gallery: Option<[Option<Image>; GALLERY_SIZE]>,
     pub player: Option<Player>,
+    pub app: Option<App>,
+    pub labels: Option<[Label; LABEL_COUNT]>,
+}
+
+/// Parse the index from a numbered tag such as `image0` or `label1`.
+fn tag_index(tag: &str, prefix: &str) -> Option<usize> {
+    tag.strip_prefix(prefix)?.parse().ok()
+}
+
+/// Update an optional property, leaving it unset if it is still empty, e.g. when
+/// its first tag is unknown or invalid.
+fn update_property<T: GraphObject + Default + PartialEq>(
+    property: &mut Option<T>,
+    data: MetaData,
+) -> Result<(), ParseError> {
+    let mut value = property.take().unwrap_or_default();
+    let result = value.update_from(data);
+    if value != T::default() {
+        *property = Some(value);
+    }
+    result
+}
+
+impl TwitterGraphObject {
+    fn update_gallery(&mut self, index: usize, data: MetaData) -> Result<(), ParseError> {
+        if index >= GALLERY_SIZE {
+            return Err(ParseError::InvalidPropertyTag);
+        }
+        let mut gallery = self.gallery.take().unwrap_or_default();
+        let result = update_property(&mut gallery[index], data);
+        if gallery.iter().any(Option::is_some) {
+            self.gallery = Some(gallery);
+        }
+        result
+    }
+
+    fn label_mut(&mut self, number: usize) -> Result<&mut Label, ParseError> {
+        // Labels are numbered from one.
+        if !(1..=LABEL_COUNT).contains(&number) {
+            return Err(ParseError::InvalidPropertyTag);
+        }
+        let labels = self.labels.get_or_insert_with(Default::default);
+        Ok(&mut labels[number - 1])
+    }
 }
 
 impl GraphObject for TwitterGraphObject {
@@ -22,41 +75,208 @@ impl GraphObject for TwitterGraphObject {
 
     fn update_from(&mut self, data: MetaData) -> Result<(), ParseError> {
         if let Some(first_tag) = data.tags.first() {
-
             if *first_tag == Image::prefix() {
-                let image = self.image.get_or_insert(Image::default());
-                image.update_from(data)?;
-                return Ok(());
+                return update_property(&mut self.image, data.next());
+            }
+
+            if let Some(index) = tag_index(first_tag, Image::prefix()) {
+                return self.update_gallery(index, data.next());
             }
 
             if *first_tag == Creator::prefix() {
-                let creator = self.creator.get_or_insert(Creator::default());
-                creator.update_from(data)?;
-                return Ok(());
+                return update_property(&mut self.creator, data.next());
             }
 
             if *first_tag == Player::prefix() {
-                let player = self.player.get_or_insert(Player::default());
-                player.update_from(data)?;
+                return update_property(&mut self.player, data.next());
+            }
+
+            if *first_tag == App::prefix() {
+                // Unknown platforms are skipped rather than leaving an empty app.
+                return update_property(&mut self.app, data.next());
+            }
+
+            if let Some(number) = tag_index(first_tag, "label") {
+                self.label_mut(number)?.label = Some(data.content.into());
+                return Ok(());
+            }
+
+            if let Some(number) = tag_index(first_tag, "data") {
+                self.label_mut(number)?.data = Some(data.content.into());
                 return Ok(());
             }
 
             match *first_tag {
+                "site" => match data.tags {
+                    [_] => self.site = Some(data.content.into()),
+                    [_, "id"] => self.site_id = Some(data.content.into()),
+                    _ => return Err(ParseError::InvalidPropertyTag),
+                },
                 "card" => {
                     self.card = Some(data.content.into());
                 }
-                "site" => {
-                    self.site = Some(data.content.into());
-                }
                 "description" => {
                     self.description = Some(data.content.into());
                 }
                 "title" => {
                     self.title = Some(data.content.into());
                 }
-                _ => {}
+                _ => return Err(ParseError::InvalidPropertyTag),
             }
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use url::Url;
+
+    use super::*;
+
+    #[test]
+    fn update_numbered() {
+        let mut graph_object = TwitterGraphObject::default();
+        let site_url = Url::parse("http://x.com").unwrap();
+
+        graph_object
+            .update_from(MetaData {
+                site_url: &site_url,
+                tags: &["image2"],
+                content: "image.jpg",
+            })
+            .unwrap();
+        let gallery = graph_object.gallery.as_ref().unwrap();
+        assert!(gallery[0].is_none());
+        assert_eq!(gallery[2].as_ref().unwrap().url, "http://x.com/image.jpg");
+
+        graph_object
+            .update_from(MetaData {
+                site_url: &site_url,
+                tags: &["label1"],
+                content: "Reading time",
+            })
+            .unwrap();
+        graph_object
+            .update_from(MetaData {
+                site_url: &site_url,
+                tags: &["data1"],
+                content: "5 min",
+            })
+            .unwrap();
+        assert_eq!(
+            graph_object.labels.as_ref().unwrap()[0],
+            Label {
+                label: Some("Reading time".into()),
+                data: Some("5 min".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn update_out_of_range() {
+        let mut graph_object = TwitterGraphObject::default();
+        let site_url = Url::parse("http://x.com").unwrap();
+
+        for tag in ["image4", "label0", "data3"] {
+            let result = graph_object.update_from(MetaData {
+                site_url: &site_url,
+                tags: &[tag],
+                content: "X",
+            });
+            assert!(result.is_err());
+        }
+        assert!(graph_object.gallery.is_none());
+        assert!(graph_object.labels.is_none());
+    }
+
+    /// Apply one invalid first tag to an empty object.
+    fn update_invalid(tags: &[&str], content: &str) -> TwitterGraphObject {
+        let mut graph_object = TwitterGraphObject::default();
+        let site_url = Url::parse("http://x.com").unwrap();
+
+        let result = graph_object.update_from(MetaData {
+            site_url: &site_url,
+            tags,
+            content,
+        });
+        assert!(result.is_err());
+        graph_object
+    }
+
+    #[test]
+    fn update_invalid_image() {
+        assert!(update_invalid(&["image", "width"], "wide").image.is_none());
+        assert!(update_invalid(&["image", "unknown"], "X").image.is_none());
+    }
+
+    #[test]
+    fn update_invalid_gallery() {
+        assert!(update_invalid(&["image1", "height"], "tall")
+            .gallery
+            .is_none());
+    }
+
+    #[test]
+    fn update_invalid_site() {
+        let graph_object = update_invalid(&["site", "unknown"], "X");
+        assert!(graph_object.site.is_none());
+        assert!(graph_object.site_id.is_none());
+    }
+
+    #[test]
+    fn update_invalid_creator() {
+        assert!(update_invalid(&["creator", "unknown"], "X")
+            .creator
+            .is_none());
+    }
+
+    #[test]
+    fn update_invalid_player() {
+        assert!(update_invalid(&["player", "width"], "wide")
+            .player
+            .is_none());
+    }
+
+    #[test]
+    fn update_invalid_keeps_existing() {
+        let mut graph_object = TwitterGraphObject::default();
+        let site_url = Url::parse("http://x.com").unwrap();
+
+        for (tags, content) in [(&["image"][..], "a.jpg"), (&["image", "width"], "wide")] {
+            let _ = graph_object.update_from(MetaData {
+                site_url: &site_url,
+                tags,
+                content,
+            });
+        }
+        assert_eq!(graph_object.image.unwrap().url, "http://x.com/a.jpg");
+    }
+
+    #[test]
+    fn update_unknown_app_platform() {
+        let mut graph_object = TwitterGraphObject::default();
+        let site_url = Url::parse("http://x.com").unwrap();
+
+        let result = graph_object.update_from(MetaData {
+            site_url: &site_url,
+            tags: &["app", "name", "windows"],
+            content: "X",
+        });
+        assert!(result.is_err());
+        assert!(graph_object.app.is_none());
+    }
+
+    #[test]
+    fn update_unknown() {
+        let mut graph_object = TwitterGraphObject::default();
+        let site_url = Url::parse("http://x.com").unwrap();
+
+        let result = graph_object.update_from(MetaData {
+            site_url: &site_url,
+            tags: &["unknown"],
+            content: "X",
+        });
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,149 @@
+use serde::Serialize;
+use std::fmt::{self, Display, Formatter};
+
+use crate::{open_graph::OpenGraphObject, twitter::TwitterGraphObject};
+
+/// A problem with a page's metadata, which consumers may render poorly.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ValidationError {
+    /// The property at fault, e.g. `og:title`.
+    pub property: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    fn new(property: &str, message: &str) -> Self {
+        Self {
+            property: property.into(),
+            message: message.into(),
+        }
+    }
+
+    fn missing(property: &str) -> Self {
+        Self::new(property, "missing required property")
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.property, self.message)
+    }
+}
+
+pub trait Validate {
+    fn validate(&self) -> Vec<ValidationError>;
+}
+
+impl Validate for OpenGraphObject {
+    /// Check the basic metadata every page requires, per ogp.me.
+    fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = vec![];
+
+        if self.title.is_none() {
+            errors.push(ValidationError::missing("og:title"));
+        }
+        if self.media_type.is_none() {
+            errors.push(ValidationError::missing("og:type"));
+        }
+        if self.images.is_none() {
+            errors.push(ValidationError::missing("og:image"));
+        }
+        if self.url.is_none() {
+            errors.push(ValidationError::missing("og:url"));
+        }
+        errors
+    }
+}
+
+/// The card types Twitter renders.
+const CARD_TYPES: &[&str] = &["summary", "summary_large_image", "app", "player"];
+
+impl Validate for TwitterGraphObject {
+    /// Check the card type and the properties it requires.
+    fn validate(&self) -> Vec<ValidationError> {
+        let mut errors = vec![];
+
+        let Some(card) = &self.card else {
+            errors.push(ValidationError::missing("twitter:card"));
+            return errors;
+        };
+
+        match card.as_str() {
+            "player" => match &self.player {
+                None => errors.push(ValidationError::missing("twitter:player")),
+                Some(player) => {
+                    if player.width.is_none() {
+                        errors.push(ValidationError::missing("twitter:player:width"));
+                    }
+                    if player.height.is_none() {
+                        errors.push(ValidationError::missing("twitter:player:height"));
+                    }
+                }
+            },
+            "app" => {
+                let has_app_id = self.app.as_ref().is_some_and(|app| {
+                    [&app.iphone, &app.ipad, &app.googleplay]
+                        .into_iter()
+                        .flatten()
+                        .any(|platform| platform.id.is_some())
+                });
+                if !has_app_id {
+                    errors.push(ValidationError::new(
+                        "twitter:app:id",
+                        "app cards require an app id for at least one platform",
+                    ));
+                }
+            }
+            card if !CARD_TYPES.contains(&card) => {
+                errors.push(ValidationError::new("twitter:card", "unknown card type"));
+            }
+            _ => {}
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::scraper::{OpenGraphScraper, Scraper, TwitterScraper};
+
+    #[test]
+    fn validate_open_graph() {
+        let result = OpenGraphScraper::scrape(
+            "http://x.com",
+            r#"<head>
+                <meta property="og:title" content="X" />
+                <meta property="og:type" content="website" />
+            </head>"#,
+        )
+        .unwrap();
+
+        let errors: Vec<String> = result.validate().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            errors,
+            vec![
+                "og:image: missing required property",
+                "og:url: missing required property"
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_twitter_player() {
+        let result = TwitterScraper::scrape(
+            "http://x.com",
+            r#"<head>
+                <meta name="twitter:card" content="player" />
+                <meta name="twitter:player" content="/player" />
+                <meta name="twitter:player:width" content="10" />
+            </head>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            result.validate(),
+            vec![ValidationError::missing("twitter:player:height")]
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use flate2::read::{DeflateDecoder, MultiGzDecoder, ZlibDecoder};
+use std::{borrow::Cow, error::Error, io::Read};
+
+use crate::content_type::{self, decode, find, find_header, parameter, split_head};
+
+/// An HTTP response, from the block of a WARC `response` record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Decode a chunked body, or `None` if it is not validly chunked.
+fn dechunk(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = vec![];
+    loop {
+        let end = find(body, b"\r\n")?;
+        let size = std::str::from_utf8(&body[..end]).ok()?;
+        // Chunk extensions follow the size after a `;`.
+        let size = size.split(';').next()?.trim();
+        let size = usize::from_str_radix(size, 16).ok()?;
+        if size == 0 {
+            return Some(decoded);
+        }
+
+        body = &body[end + 2..];
+        decoded.extend_from_slice(body.get(..size)?);
+        body = body[size..].strip_prefix(b"\r\n")?;
+    }
+}
+
+/// Remove a `Content-Encoding`, or `None` if a coding is unsupported or invalid.
+fn decode_content(body: &[u8], encoding: &str) -> Option<Vec<u8>> {
+    let mut body = body.to_vec();
+    // Codings are listed in the order they were applied.
+    for coding in encoding.split(',').rev().map(str::trim) {
+        let mut decoded = vec![];
+        let result = match coding.to_ascii_lowercase().as_str() {
+            "" | "identity" => continue,
+            "gzip" | "x-gzip" => MultiGzDecoder::new(&body[..]).read_to_end(&mut decoded),
+            // `deflate` should be zlib wrapped, but some servers send it raw.
+            "deflate" => ZlibDecoder::new(&body[..])
+                .read_to_end(&mut decoded)
+                .or_else(|_| {
+                    decoded.clear();
+                    DeflateDecoder::new(&body[..]).read_to_end(&mut decoded)
+                }),
+            _ => return None,
+        };
+        result.ok()?;
+        body = decoded;
+    }
+    Some(body)
+}
+
+impl HttpResponse {
+    pub fn parse(block: &[u8]) -> Result<Self, Box<dyn Error>> {
+        let (head, body) = split_head(block);
+
+        let head = String::from_utf8_lossy(head);
+        let mut lines = head.lines();
+
+        let status_line = lines.next().unwrap_or_default();
+        let status = status_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(|| format!("invalid HTTP status line, {}", status_line))?;
+
+        let headers: Vec<_> = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+            .collect();
+
+        let is_chunked = find_header(&headers, "Transfer-Encoding")
+            .is_some_and(|encoding| encoding.eq_ignore_ascii_case("chunked"));
+        // Some writers decode the body but keep the header, so fall back to the raw body.
+        let body = match is_chunked {
+            true => dechunk(body).unwrap_or_else(|| body.to_vec()),
+            false => body.to_vec(),
+        };
+        let body = match find_header(&headers, "Content-Encoding") {
+            Some(encoding) => decode_content(&body, encoding).unwrap_or(body),
+            None => body,
+        };
+
+        Ok(Self {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// The media type from `Content-Type`, e.g. `text/html`.
+    pub fn media_type(&self) -> Option<&str> {
+        self.header("Content-Type").map(content_type::media_type)
+    }
+
+    /// The charset from `Content-Type`, e.g. `ISO-8859-1`.
+    pub fn charset(&self) -> Option<&str> {
+        parameter(self.header("Content-Type")?, "charset")
+    }
+
+    pub fn is_html(&self) -> bool {
+        self.header("Content-Type")
+            .is_some_and(content_type::is_html)
+    }
+
+    /// Decode the body with its charset, defaulting to UTF-8 and preferring a BOM.
+    pub fn text(&self) -> Cow<'_, str> {
+        decode(&self.body, self.header("Content-Type").unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use flate2::{write::ZlibEncoder, Compression};
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let response = HttpResponse::parse(
+            b"HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=\"ISO-8859-1\"\r\n\r\ncaf\xe9",
+        )
+        .unwrap();
+
+        assert_eq!(response.status, 200);
+        assert!(response.is_html());
+        assert_eq!(response.charset(), Some("ISO-8859-1"));
+        assert_eq!(response.text(), "caf\u{e9}");
+    }
+
+    #[test]
+    fn parse_chunked() {
+        let response = HttpResponse::parse(
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2;x=y\r\nde\r\n0\r\n\r\n",
+        )
+        .unwrap();
+
+        assert_eq!(response.body, b"abcde");
+        assert_eq!(response.text(), "abcde");
+    }
+
+    #[test]
+    fn parse_content_encoding() {
+        let mut encoder = ZlibEncoder::new(vec![], Compression::default());
+        encoder.write_all(b"<p>deflate</p>").unwrap();
+        let mut block = b"HTTP/1.1 200 OK\r\nContent-Encoding: deflate\r\n\r\n".to_vec();
+        block.extend(encoder.finish().unwrap());
+
+        let response = HttpResponse::parse(&block).unwrap();
+        assert_eq!(response.text(), "<p>deflate</p>");
+
+        // Bodies already decoded by the writer are kept.
+        let response =
+            HttpResponse::parse(b"HTTP/1.1 200 OK\r\nContent-Encoding: gzip\r\n\r\n<p></p>")
+                .unwrap();
+        assert_eq!(response.body, b"<p></p>");
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(HttpResponse::parse(b"not http").is_err());
+    }
+}
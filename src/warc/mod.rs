@@ -0,0 +1,8 @@
+mod http;
+pub use http::HttpResponse;
+
+mod page;
+pub use page::WarcPage;
+
+mod record;
+pub use record::{WarcReader, WarcRecord};
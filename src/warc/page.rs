@@ -0,0 +1,182 @@
+use std::error::Error;
+
+use super::{HttpResponse, WarcReader, WarcRecord};
+use crate::scraper::Scraper;
+
+/// An HTML page from a WARC `response` record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WarcPage {
+    /// The record's `WARC-Target-URI`, used as the site url.
+    pub target_uri: String,
+    pub html: String,
+}
+
+impl WarcPage {
+    /// Get the page from a record, or `None` for records which are not HTML responses.
+    pub fn from_record(record: &WarcRecord) -> Result<Option<Self>, Box<dyn Error>> {
+        if record.record_type() != Some("response") {
+            return Ok(None);
+        }
+        // Responses may also be DNS or other protocols.
+        let is_http = record
+            .header("Content-Type")
+            .is_some_and(|content_type| content_type.starts_with("application/http"));
+        if !is_http {
+            return Ok(None);
+        }
+
+        let response = HttpResponse::parse(&record.block)?;
+        if !response.is_html() {
+            return Ok(None);
+        }
+
+        let target_uri = record
+            .target_uri()
+            .ok_or("missing WARC-Target-URI")?
+            .to_string();
+        Ok(Some(Self {
+            target_uri,
+            html: response.text().into_owned(),
+        }))
+    }
+}
+
+impl WarcReader {
+    /// Iterate the HTML pages, skipping other records.
+    pub fn pages(self) -> impl Iterator<Item = Result<WarcPage, Box<dyn Error>>> {
+        self.filter_map(|record| {
+            record
+                .and_then(|record| WarcPage::from_record(&record))
+                .transpose()
+        })
+    }
+
+    /// Scrape each HTML page, yielding its target uri and graph object.
+    pub fn scrape<S: Scraper>(
+        self,
+    ) -> impl Iterator<Item = Result<(String, S::RootGraphObject), Box<dyn Error>>> {
+        self.pages().map(|page| {
+            let page = page?;
+            let object = S::scrape(&page.target_uri, &page.html)?;
+            Ok((page.target_uri, object))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::{self, Write};
+
+    use super::*;
+    use crate::OpenGraphScraper;
+
+    fn record(headers: &str, block: &[u8]) -> Vec<u8> {
+        let mut record = format!(
+            "WARC/1.1\r\n{}Content-Length: {}\r\n\r\n",
+            headers,
+            block.len()
+        )
+        .into_bytes();
+        record.extend_from_slice(block);
+        record.extend_from_slice(b"\r\n\r\n");
+        record
+    }
+
+    fn response(uri: &str, content_type: &str, body: &[u8]) -> Vec<u8> {
+        response_with(uri, &format!("Content-Type: {}\r\n", content_type), body)
+    }
+
+    fn response_with(uri: &str, headers: &str, body: &[u8]) -> Vec<u8> {
+        let mut block = format!("HTTP/1.1 200 OK\r\n{}\r\n", headers).into_bytes();
+        block.extend_from_slice(body);
+
+        record(
+            &format!(
+                "WARC-Type: response\r\nWARC-Target-URI: {}\r\n\
+                Content-Type: application/http; msgtype=response\r\n",
+                uri
+            ),
+            &block,
+        )
+    }
+
+    fn records() -> Vec<Vec<u8>> {
+        vec![
+            record("WARC-Type: warcinfo\r\n", b"software: test"),
+            record(
+                "WARC-Type: request\r\nWARC-Target-URI: http://x.com/a\r\n",
+                b"GET /a HTTP/1.1\r\n\r\n",
+            ),
+            response(
+                "http://x.com/a",
+                "text/html; charset=windows-1252",
+                b"<head><meta property=\"og:title\" content=\"Caf\xe9\" />\
+                <meta property=\"og:image\" content=\"/a.jpg\" /></head>",
+            ),
+            response("http://x.com/a.jpg", "image/jpeg", b"\xff\xd8"),
+            response(
+                "<http://x.com/b>",
+                "text/html",
+                b"<head><meta property=\"og:title\" content=\"B\" /></head>",
+            ),
+        ]
+    }
+
+    fn assert_scraped(reader: WarcReader) {
+        let results: Vec<_> = reader
+            .scrape::<OpenGraphScraper>()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "http://x.com/a");
+        assert_eq!(results[0].1.title, Some("Caf\u{e9}".into()));
+        assert_eq!(
+            results[0].1.images.as_ref().unwrap()[0].url,
+            "http://x.com/a.jpg"
+        );
+        assert_eq!(results[1].0, "http://x.com/b");
+        assert_eq!(results[1].1.title, Some("B".into()));
+    }
+
+    #[test]
+    fn scrape() {
+        assert_scraped(WarcReader::new(io::Cursor::new(records().concat())).unwrap());
+    }
+
+    #[test]
+    fn scrape_gzip() {
+        let compressed: Vec<u8> = records()
+            .iter()
+            .flat_map(|record| {
+                let mut encoder = GzEncoder::new(vec![], Compression::default());
+                encoder.write_all(record).unwrap();
+                encoder.finish().unwrap()
+            })
+            .collect();
+
+        assert_scraped(WarcReader::new(io::Cursor::new(compressed)).unwrap());
+    }
+
+    #[test]
+    fn scrape_content_encoding() {
+        let mut encoder = GzEncoder::new(vec![], Compression::default());
+        encoder
+            .write_all(b"<head><meta property=\"og:title\" content=\"Gzip\" /></head>")
+            .unwrap();
+        let record = response_with(
+            "http://x.com/",
+            "Content-Type: text/html\r\nContent-Encoding: gzip\r\n",
+            &encoder.finish().unwrap(),
+        );
+
+        let results: Vec<_> = WarcReader::new(io::Cursor::new(record))
+            .unwrap()
+            .scrape::<OpenGraphScraper>()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(results[0].1.title, Some("Gzip".into()));
+    }
+}
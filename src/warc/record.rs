@@ -0,0 +1,163 @@
+use flate2::bufread::MultiGzDecoder;
+use std::{
+    error::Error,
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::Path,
+};
+
+use crate::content_type::find_header;
+
+/// A WARC record's named fields and content block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WarcRecord {
+    /// The version line, e.g. `WARC/1.1`.
+    pub version: String,
+    pub headers: Vec<(String, String)>,
+    pub block: Vec<u8>,
+}
+
+impl WarcRecord {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// The record type, e.g. `response` or `request`.
+    pub fn record_type(&self) -> Option<&str> {
+        self.header("WARC-Type")
+    }
+
+    /// The target uri, without the angle brackets some writers add.
+    pub fn target_uri(&self) -> Option<&str> {
+        self.header("WARC-Target-URI")
+            .map(|uri| uri.trim_start_matches('<').trim_end_matches('>'))
+    }
+}
+
+/// Reads records from a WARC file, which may be gzip compressed per record.
+pub struct WarcReader {
+    reader: Box<dyn BufRead>,
+    /// Set after an error, as the position of the next record is unknown.
+    failed: bool,
+}
+
+impl WarcReader {
+    /// Read records, detecting gzip compression from the first bytes.
+    pub fn new(reader: impl Read + 'static) -> io::Result<Self> {
+        let mut reader = BufReader::new(reader);
+        let is_gzip = reader.fill_buf()?.starts_with(&[0x1f, 0x8b]);
+
+        let reader: Box<dyn BufRead> = if is_gzip {
+            Box::new(BufReader::new(MultiGzDecoder::new(reader)))
+        } else {
+            Box::new(reader)
+        };
+        Ok(Self {
+            reader,
+            failed: false,
+        })
+    }
+
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(File::open(path)?)
+    }
+
+    fn read_record(&mut self) -> Result<Option<WarcRecord>, Box<dyn Error>> {
+        // Skip the blank lines ending the previous record.
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+            if !line.trim().is_empty() {
+                break;
+            }
+        }
+
+        let version = line.trim_end().to_string();
+        if !version.starts_with("WARC/") {
+            return Err(format!("invalid WARC version, {}", version).into());
+        }
+
+        let mut headers = vec![];
+        loop {
+            line.clear();
+            if self.reader.read_line(&mut line)? == 0 {
+                return Err("unexpected end of WARC headers".into());
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+
+            let (name, value) = line
+                .split_once(':')
+                .ok_or_else(|| format!("invalid WARC header, {}", line))?;
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+
+        let length: u64 = find_header(&headers, "Content-Length")
+            .and_then(|length| length.parse().ok())
+            .ok_or("missing or invalid WARC Content-Length")?;
+
+        let mut block = vec![];
+        (&mut self.reader).take(length).read_to_end(&mut block)?;
+        if block.len() as u64 != length {
+            return Err("truncated WARC record".into());
+        }
+
+        Ok(Some(WarcRecord {
+            version,
+            headers,
+            block,
+        }))
+    }
+}
+
+impl Iterator for WarcReader {
+    type Item = Result<WarcRecord, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let result = self.read_record();
+        self.failed = result.is_err();
+        result.transpose()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read() {
+        let warc = "WARC/1.1\r\nWARC-Type: warcinfo\r\nContent-Length: 4\r\n\r\ninfo\r\n\r\n\
+            WARC/1.1\r\nWARC-Type: response\r\nWARC-Target-URI: <http://x.com/>\r\n\
+            Content-Length: 2\r\n\r\nab\r\n\r\n";
+
+        let records: Vec<_> = WarcReader::new(warc.as_bytes())
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].record_type(), Some("warcinfo"));
+        assert_eq!(records[0].block, b"info");
+        assert_eq!(records[1].target_uri(), Some("http://x.com/"));
+        assert_eq!(records[1].header("content-length"), Some("2"));
+    }
+
+    #[test]
+    fn read_truncated() {
+        let warc = "WARC/1.1\r\nContent-Length: 10\r\n\r\nab";
+
+        let mut reader = WarcReader::new(warc.as_bytes()).unwrap();
+
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+}
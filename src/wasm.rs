@@ -0,0 +1,15 @@
+use serde::Serialize;
+use serde_wasm_bindgen::Serializer;
+use wasm_bindgen::prelude::*;
+
+use crate::PreviewScraper;
+
+/// Scrape a page's link preview, as an object in the same shape as its JSON.
+#[wasm_bindgen]
+pub fn scrape(url: &str, html: &str) -> Result<JsValue, JsError> {
+    let preview =
+        PreviewScraper::scrape(url, html).map_err(|error| JsError::new(&error.to_string()))?;
+
+    // Serialize maps as plain objects and `None` as `null`, as `JSON.parse` would.
+    Ok(preview.serialize(&Serializer::json_compatible())?)
+}
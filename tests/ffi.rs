@@ -0,0 +1,128 @@
+//! Calls the C API through its C declarations, as an embedder would.
+#![cfg(feature = "cdylib")]
+
+use std::{
+    ffi::{c_char, c_int, CStr},
+    ptr,
+};
+
+// Links the library, which the declarations below resolve against.
+use metagraph::ffi::MetagraphError;
+
+#[repr(C)]
+struct MetagraphPreview {
+    _private: [u8; 0],
+}
+
+extern "C" {
+    fn metagraph_scrape(
+        url: *const c_char,
+        html: *const c_char,
+        len: usize,
+        error: *mut MetagraphError,
+    ) -> *mut MetagraphPreview;
+    fn metagraph_preview_title(preview: *const MetagraphPreview) -> *const c_char;
+    fn metagraph_preview_image(preview: *const MetagraphPreview) -> *const c_char;
+    fn metagraph_preview_description(preview: *const MetagraphPreview) -> *const c_char;
+    fn metagraph_preview_json(
+        preview: *const MetagraphPreview,
+        error: *mut MetagraphError,
+    ) -> *mut c_char;
+    fn metagraph_preview_free(preview: *mut MetagraphPreview);
+    fn metagraph_string_free(string: *mut c_char);
+    fn metagraph_error_message(error: c_int) -> *const c_char;
+}
+
+const HTML: &str = r#"<head>
+    <meta property="og:title" content="Title" />
+    <meta property="og:image" content="/a.jpg" />
+</head>"#;
+
+unsafe fn text(text: *const c_char) -> Option<String> {
+    (!text.is_null()).then(|| CStr::from_ptr(text).to_string_lossy().into_owned())
+}
+
+#[test]
+fn scrape() {
+    unsafe {
+        let mut error = MetagraphError::Serialize;
+        let preview = metagraph_scrape(
+            c"http://x.com".as_ptr(),
+            HTML.as_ptr().cast(),
+            HTML.len(),
+            &mut error,
+        );
+        assert_eq!(error, MetagraphError::Ok);
+        assert!(!preview.is_null());
+
+        assert_eq!(text(metagraph_preview_title(preview)), Some("Title".into()));
+        assert_eq!(
+            text(metagraph_preview_image(preview)),
+            Some("http://x.com/a.jpg".into())
+        );
+        assert_eq!(text(metagraph_preview_description(preview)), None);
+
+        let json = metagraph_preview_json(preview, &mut error);
+        assert_eq!(error, MetagraphError::Ok);
+        let value: serde_json::Value = serde_json::from_str(&text(json).unwrap()).unwrap();
+        assert_eq!(value["open_graph"]["title"], "Title");
+
+        metagraph_string_free(json);
+        metagraph_preview_free(preview);
+    }
+}
+
+#[test]
+fn scrape_errors() {
+    unsafe {
+        let mut error = MetagraphError::Ok;
+
+        let preview = metagraph_scrape(
+            c"not a url".as_ptr(),
+            HTML.as_ptr().cast(),
+            HTML.len(),
+            &mut error,
+        );
+        assert!(preview.is_null());
+        assert_eq!(error, MetagraphError::InvalidUrl);
+        assert_eq!(
+            text(metagraph_error_message(error as c_int)),
+            Some("invalid url".into())
+        );
+        for code in [-1, 9, c_int::MAX] {
+            assert_eq!(
+                text(metagraph_error_message(code)),
+                Some("unknown error".into())
+            );
+        }
+
+        let preview = metagraph_scrape(ptr::null(), ptr::null(), 0, &mut error);
+        assert!(preview.is_null());
+        assert_eq!(error, MetagraphError::NullArgument);
+
+        let invalid = [0xff, 0xfe];
+        let preview = metagraph_scrape(
+            c"http://x.com".as_ptr(),
+            invalid.as_ptr().cast(),
+            invalid.len(),
+            ptr::null_mut(),
+        );
+        assert!(preview.is_null());
+
+        // Null handles are ignored.
+        assert!(metagraph_preview_title(ptr::null()).is_null());
+        metagraph_preview_free(ptr::null_mut());
+        metagraph_string_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn header_up_to_date() {
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/metagraph.h"));
+    let checked_in = include_str!("../include/metagraph.h");
+    assert!(
+        generated == checked_in,
+        "include/metagraph.h is out of date, copy {}/metagraph.h over it",
+        env!("OUT_DIR")
+    );
+}
@@ -0,0 +1,38 @@
+//! Run under node with `cargo test --target wasm32-unknown-unknown --features wasm --test wasm`.
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+use metagraph::wasm::scrape;
+
+fn get(value: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(value, &key.into()).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn scrape_preview() {
+    let preview = scrape(
+        "http://x.com",
+        r#"<head>
+            <meta property="og:title" content="Title" />
+            <meta property="og:image" content="/a.jpg" />
+        </head>"#,
+    )
+    .unwrap();
+
+    assert_eq!(get(&preview, "title"), "Title");
+    assert_eq!(get(&preview, "image"), "http://x.com/a.jpg");
+    assert!(get(&preview, "description").is_null());
+
+    let images = get(&get(&preview, "open_graph"), "images");
+    assert_eq!(
+        get(&js_sys::Array::from(&images).get(0), "url"),
+        "http://x.com/a.jpg"
+    );
+}
+
+#[wasm_bindgen_test]
+fn scrape_invalid_url() {
+    assert!(scrape("not a url", "").is_err());
+}